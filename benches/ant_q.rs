@@ -0,0 +1,82 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::Rng;
+
+use cea::{
+    ant_q_solver::AntQSolver,
+    candidate_lists::CandidateStrategy,
+    instance::{CoordinateInstance, Metric},
+    models::{IterativeSolver, Solver},
+    rand_utils::{random_provider, RngAlgorithm},
+};
+
+const NODES_COUNT: usize = 1000;
+const RANDOM_SEED: Option<u64> = Some(42);
+
+fn random_instance(nodes_count: usize) -> CoordinateInstance<f64> {
+    let mut random_provider = random_provider(RngAlgorithm::Pcg64, RANDOM_SEED);
+    let coordinates = (0..nodes_count)
+        .map(|_| {
+            (
+                random_provider.gen_range(0.0..1000.0),
+                random_provider.gen_range(0.0..1000.0),
+            )
+        })
+        .collect();
+
+    CoordinateInstance::new(coordinates, Metric::Euclidean)
+}
+
+fn ant_q_iterations(criterion: &mut Criterion) {
+    let instance = random_instance(NODES_COUNT);
+    let exponents = [("integer", 1.0, 2.0), ("fractional", 1.5, 2.5)];
+
+    let mut group = criterion.benchmark_group("ant_q_1000");
+    group.sample_size(10);
+
+    for (name, pheromone_importance, destination_importance) in exponents {
+        let solver = AntQSolver::new(
+            5,
+            10,
+            RANDOM_SEED,
+            pheromone_importance,
+            destination_importance,
+            1.0,
+            0.9,
+        );
+        group.bench_with_input(
+            BenchmarkId::new("full", name),
+            &solver,
+            |bencher, solver| bencher.iter(|| solver.solve(&instance).score()),
+        );
+
+        let mut state = solver.iterations(&instance).unwrap();
+        group.bench_function(BenchmarkId::new("update_full", name), |bencher| {
+            bencher.iter(|| {
+                state.update_probability_matrix();
+                black_box(&state);
+            })
+        });
+
+        let solver = solver.with_candidate_lists(CandidateStrategy::Nearest, 20);
+        group.bench_with_input(
+            BenchmarkId::new("candidates", name),
+            &solver,
+            |bencher, solver| bencher.iter(|| solver.solve(&instance).score()),
+        );
+
+        let mut state = solver.iterations(&instance).unwrap();
+        group.bench_function(BenchmarkId::new("update_candidates", name), |bencher| {
+            bencher.iter(|| {
+                state.update_probability_matrix();
+                black_box(&state);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, ant_q_iterations);
+criterion_main!(benches);
@@ -1,17 +1,43 @@
-use rand::{distributions::Standard, rngs::StdRng, Rng};
+use std::{
+    path::{Path, PathBuf},
+    thread,
+    time::Instant,
+};
+
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    models::{AdjMatrix, Solver, VisitedVecExt, Way, WayVecExt},
-    rand_utils::{random_provider, RngDistributionExt},
+    candidate_lists::{CandidateLists, CandidateStrategy},
+    checkpoint::{Checkpoint, CheckpointError, Parameters},
+    convergence::{
+        branching_factor, entropy, Convergence, Restart, RestartCriterion, RestartStrategy,
+    },
+    greedy_solver::GreedySolver,
+    instance::{Instance, ScoreOf},
+    instance_analysis::Symmetry,
+    matrix::Matrix,
+    models::{
+        population_diversity, IterativeSolver, Snapshot, Solution, SolveError, Solver,
+        VisitedVecExt, Way, WayVecExt,
+    },
+    observer::{Observer, Progress},
+    rand_utils::{random_provider, RandomProvider, RngAlgorithm, RngDistributionExt, UnitInterval},
+    termination::{SearchStatus, StopReason, Termination},
+    weight::{Score, Weight},
 };
 
-struct AlgorithmState<'a> {
-    adj_matrix: &'a AdjMatrix<u32>,
-    reverse_distance_matrix: Vec<Vec<f64>>,
+pub struct AlgorithmState<'a, I: Instance> {
+    instance: &'a I,
+    heuristic_matrix: Matrix<f64>,
+    symmetry: Symmetry,
+    candidate_lists: Option<CandidateLists>,
 
-    max_iteration: u32,
+    termination: Termination,
     population_size: usize,
-    random_provider: StdRng,
+    threads_count: usize,
+    rng_algorithm: RngAlgorithm,
+    random_provider: RandomProvider,
 
     pheromone_importance: f64,
     destination_importance: f64,
@@ -19,122 +45,408 @@ struct AlgorithmState<'a> {
     pheromone_evaporation: f64,
 
     iteration: u32,
-    pheromone_matrix: Vec<Vec<f64>>,
-    probability_matrix: Vec<Vec<f64>>,
-    best_way: Option<Way<'a>>,
+    pheromone_matrix: Matrix<f64>,
+    initial_pheromone_matrix: Option<Matrix<f64>>,
+    default_pheromone: f64,
+    min_pheromone: f64,
+    probability_matrix: Matrix<f64>,
+    best_way: Option<Way<'a, I>>,
+
+    start: Instant,
+    evaluations: u64,
+    stagnant_iterations: u32,
+    stop_reason: Option<StopReason>,
+
+    restarts: Option<(RestartCriterion, RestartStrategy)>,
+    restarts_count: u32,
+    last_restart_iteration: u32,
+    restart: Option<Restart>,
+
+    track_diversity: bool,
+    iteration_best_way: Option<Way<'a, I>>,
+    diversity: Option<f64>,
 }
 
-impl<'a> Iterator for AlgorithmState<'a> {
-    type Item = Way<'a>;
+impl<'a, I: Instance> Iterator for AlgorithmState<'a, I> {
+    type Item = Snapshot<'a, I>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.iteration >= self.max_iteration {
-            return None;
+        if self.iteration > 0 {
+            if let Some(stop_reason) = self.termination.check(&self.search_status()) {
+                self.stop_reason = Some(stop_reason);
+                return None;
+            }
         }
         self.iteration += 1;
+        self.restart = None;
 
-        self.recalculate_probability_matrix();
+        self.update_probability_matrix();
         let population = self.build_population();
+        self.evaluations += population.len() as u64;
 
         self.spread_pheromone(&population);
+        if self.track_diversity || self.restarts.is_some() {
+            self.diversity = Some(population_diversity(&population));
+        }
 
         let iteration_best_way = population.into_iter().min().unwrap();
-        let best_way = self.global_best_way(iteration_best_way);
+        self.iteration_best_way = Some(iteration_best_way.clone());
+        let best_way = self.global_best_way(iteration_best_way.clone());
+        self.check_restart();
 
-        Some(best_way)
+        Some(Snapshot::new(self.iteration, best_way, iteration_best_way))
     }
 }
 
-impl<'a> AlgorithmState<'a> {
+impl<'a, I: Instance> AlgorithmState<'a, I> {
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    pub fn best_way(&self) -> Option<&Way<'a, I>> {
+        self.best_way.as_ref()
+    }
+
+    pub fn iteration_best_way(&self) -> Option<&Way<'a, I>> {
+        self.iteration_best_way.as_ref()
+    }
+
+    pub fn pheromone_matrix(&self) -> &Matrix<f64> {
+        &self.pheromone_matrix
+    }
+
+    pub fn pheromone(&self, from: usize, to: usize) -> f64 {
+        self.edge_column(from, to)
+            .map_or(self.default_pheromone, |column| {
+                self.pheromone_matrix[from][column]
+            })
+    }
+
+    pub fn stop_reason(&self) -> Option<StopReason> {
+        self.stop_reason
+    }
+
+    pub fn restart(&self) -> Option<&Restart> {
+        self.restart.as_ref()
+    }
+
+    pub fn restarts_count(&self) -> u32 {
+        self.restarts_count
+    }
+
+    pub fn convergence(&self) -> Convergence {
+        const BRANCHING_FACTOR_LAMBDA: f64 = 0.05;
+
+        let nodes_count = self.instance.nodes_count().max(1) as f64;
+        let branches_count: usize = (0..self.instance.nodes_count())
+            .map(|node| branching_factor(self.neighbor_pheromones(node), BRANCHING_FACTOR_LAMBDA))
+            .sum();
+        let entropy_sum: f64 = (0..self.instance.nodes_count())
+            .map(|node| entropy(self.neighbor_pheromones(node)))
+            .sum();
+
+        Convergence {
+            branching_factor: branches_count as f64 / nodes_count,
+            entropy: entropy_sum / nodes_count,
+            diversity: self.diversity.unwrap_or_default(),
+            stagnant_iterations: self
+                .stagnant_iterations
+                .min(self.iteration - self.last_restart_iteration),
+        }
+    }
+
+    pub fn with_diversity_tracking(mut self) -> Self {
+        self.track_diversity = true;
+        self
+    }
+
+    pub fn progress(&self) -> Option<Progress<ScoreOf<I>>> {
+        Some(Progress {
+            iteration: self.iteration,
+            iteration_best_score: self.iteration_best_way.as_ref()?.score(),
+            best_score: self.best_way.as_ref()?.score(),
+            elapsed: self.start.elapsed(),
+            diversity: self.diversity,
+            restarts: self.restarts_count,
+        })
+    }
+
+    fn to_checkpoint(&self, parameters: Parameters) -> Checkpoint {
+        Checkpoint {
+            parameters,
+            iteration: self.iteration,
+            evaluations: self.evaluations,
+            stagnant_iterations: self.stagnant_iterations,
+            restarts: self.restarts_count,
+            last_restart_iteration: self.last_restart_iteration,
+            elapsed: self.start.elapsed(),
+            pheromone_matrix: self.pheromone_matrix.clone(),
+            best_way: self.best_way.as_ref().map(|way| way.way().clone()),
+            random_provider: self.random_provider.clone(),
+        }
+    }
+
+    fn restore(&mut self, checkpoint: Checkpoint) -> Result<(), CheckpointError> {
+        let nodes_count = self.instance.nodes_count();
+        let pheromone_matrix = &checkpoint.pheromone_matrix;
+        let columns_count = self.pheromone_matrix.columns_count();
+        if pheromone_matrix.rows_count() != nodes_count
+            || pheromone_matrix.columns_count() != columns_count
+            || pheromone_matrix.as_slice().len() != nodes_count * columns_count
+        {
+            return Err(CheckpointError::NodesCountMismatch {
+                expected: nodes_count,
+                actual: checkpoint.nodes_count(),
+            });
+        }
+
+        let best_way = match checkpoint.best_way {
+            Some(way) => {
+                Some(Way::try_new(self.instance, way).map_err(CheckpointError::InvalidBestWay)?)
+            }
+            None => None,
+        };
+
+        self.iteration = checkpoint.iteration;
+        self.evaluations = checkpoint.evaluations;
+        self.stagnant_iterations = checkpoint.stagnant_iterations;
+        self.restarts_count = checkpoint.restarts;
+        self.last_restart_iteration = checkpoint.last_restart_iteration;
+        self.start = Instant::now()
+            .checked_sub(checkpoint.elapsed)
+            .unwrap_or_else(Instant::now);
+        self.pheromone_matrix = checkpoint.pheromone_matrix;
+        self.best_way = best_way;
+        self.random_provider = checkpoint.random_provider;
+
+        Ok(())
+    }
+
+    fn search_status(&self) -> SearchStatus {
+        SearchStatus {
+            iteration: self.iteration,
+            stagnant_iterations: self.stagnant_iterations,
+            evaluations: self.evaluations,
+            elapsed: self.start.elapsed(),
+            best_score: self.best_way.as_ref().map(|way| way.score().to_f64()),
+        }
+    }
+
+    fn check_restart(&mut self) {
+        let (Some((criterion, strategy)), Some(initial_pheromone_matrix)) =
+            (&self.restarts, &self.initial_pheromone_matrix)
+        else {
+            return;
+        };
+
+        let convergence = self.convergence();
+        if let Some(reason) = criterion.check(&convergence) {
+            strategy.apply(&mut self.pheromone_matrix, initial_pheromone_matrix);
+
+            self.restarts_count += 1;
+            self.last_restart_iteration = self.iteration;
+            self.restart = Some(Restart {
+                iteration: self.iteration,
+                reason,
+                convergence,
+            });
+        }
+    }
+
+    #[doc(hidden)]
     #[inline]
-    fn recalculate_probability_matrix(&mut self) {
-        let nodes_count = self.adj_matrix.len();
+    pub fn update_probability_matrix(&mut self) {
+        let probabilities = self.probability_matrix.as_mut_slice().iter_mut();
+        let pheromones = self.pheromone_matrix.as_slice().iter();
+        let heuristics = self.heuristic_matrix.as_slice().iter();
 
-        for row in 0..nodes_count {
-            for column in 0..nodes_count {
-                let first = self.pheromone_matrix[row][column].powf(self.pheromone_importance);
-                let second =
-                    self.reverse_distance_matrix[row][column].powf(self.destination_importance);
+        for ((probability, pheromone), heuristic) in probabilities.zip(pheromones).zip(heuristics) {
+            *probability = power(*pheromone, self.pheromone_importance) * heuristic;
+        }
+    }
 
-                self.probability_matrix[row][column] = first * second;
+    #[inline]
+    fn choice_info(&self, from: usize, to: usize) -> f64 {
+        match self.edge_column(from, to) {
+            Some(column) => self.probability_matrix[from][column],
+            None => {
+                power(self.default_pheromone, self.pheromone_importance)
+                    * power(
+                        1.0 / self.instance.distance(from, to).to_f64(),
+                        self.destination_importance,
+                    )
             }
         }
     }
 
     #[inline]
-    fn build_population(&mut self) -> Vec<Way<'a>> {
-        let population_size = self.population_size;
-        let mut ant_ways: Vec<Way<'a>> = Vec::with_capacity(self.population_size);
+    fn edge_column(&self, from: usize, to: usize) -> Option<usize> {
+        match &self.candidate_lists {
+            Some(candidate_lists) => candidate_lists.position(from, to),
+            None => Some(to),
+        }
+    }
 
-        for _ in 0..population_size {
-            let way = self.generate_ant_way();
-            ant_ways.push(way);
+    fn neighbor_pheromones(&self, node: usize) -> impl Iterator<Item = f64> + Clone + '_ {
+        let pheromones = self.pheromone_matrix.row(node);
+        let (pheromones, excluded) = match &self.candidate_lists {
+            Some(candidate_lists) => (&pheromones[..candidate_lists.candidates(node).len()], None),
+            None => (pheromones, Some(node)),
+        };
+
+        pheromones
+            .iter()
+            .enumerate()
+            .filter(move |(column, _)| Some(*column) != excluded)
+            .map(|(_, pheromone)| *pheromone)
+    }
+
+    #[inline]
+    fn build_population(&mut self) -> Vec<Way<'a, I>> {
+        let population_size = self.population_size;
+        let ant_seeds: Vec<u64> = (0..population_size)
+            .map(|_| self.random_provider.next_u64())
+            .collect();
+
+        let threads_count = self.threads_count.clamp(1, population_size.max(1));
+        if threads_count == 1 {
+            return ant_seeds
+                .into_iter()
+                .map(|seed| {
+                    self.generate_ant_way(&mut random_provider(self.rng_algorithm, Some(seed)))
+                })
+                .collect();
         }
 
-        ant_ways
+        let state = &*self;
+        let chunk_size = population_size.div_ceil(threads_count);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = ant_seeds
+                .chunks(chunk_size)
+                .map(|seeds| {
+                    scope.spawn(move || {
+                        seeds
+                            .iter()
+                            .map(|seed| {
+                                state.generate_ant_way(&mut random_provider(
+                                    state.rng_algorithm,
+                                    Some(*seed),
+                                ))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        })
     }
 
     #[inline]
-    fn generate_ant_way(&mut self) -> Way<'a> {
-        let adj_matrix = self.adj_matrix;
-        let nodes_count = adj_matrix.len();
+    fn generate_ant_way(&self, random_provider: &mut RandomProvider) -> Way<'a, I> {
+        let instance = self.instance;
+        let nodes_count = instance.nodes_count();
 
-        let start_node = self.random_provider.gen_range(0..nodes_count);
+        let start_node = random_provider.uniform_below(nodes_count as u64) as usize;
         let mut node = start_node;
 
         let mut visited = vec![false; nodes_count];
         let mut way = Vec::with_capacity(nodes_count);
+        let mut buffer = Vec::with_capacity(nodes_count);
 
         loop {
             visited[node] = true;
             way.push(node);
 
-            node = match self.find_next_node(node, &mut visited) {
+            node = match self.find_next_node(random_provider, node, &mut visited, &mut buffer) {
                 Some(next_node) => next_node,
                 None => break,
             };
         }
         way.push(start_node);
 
-        Way::new(adj_matrix, way)
+        Way::new(instance, way)
     }
 
     #[inline]
-    fn find_next_node(&mut self, node: usize, visited: &mut Vec<bool>) -> Option<usize> {
-        self.random_provider
-            .distribute_by_key(visited.available_neighbors(), Standard, |key| {
-                self.probability_matrix[node][*key]
-            })
+    fn find_next_node(
+        &self,
+        random_provider: &mut RandomProvider,
+        node: usize,
+        visited: &mut Vec<bool>,
+        buffer: &mut Vec<(f64, usize)>,
+    ) -> Option<usize> {
+        if let Some(candidate_lists) = &self.candidate_lists {
+            let candidates = candidate_lists.candidates(node);
+            let columns = (0..candidates.len()).filter(|column| !visited[candidates[*column]]);
+
+            let next_column = random_provider.distribute_by_key_with_buffer(
+                columns,
+                UnitInterval,
+                |column| self.probability_matrix[node][*column],
+                buffer,
+            );
+            if let Some(column) = next_column {
+                return Some(candidates[column]);
+            }
+        }
+
+        if self.candidate_lists.is_some() {
+            return random_provider.distribute_by_key_with_buffer(
+                visited.available_neighbors(),
+                UnitInterval,
+                |key| self.choice_info(node, *key),
+                buffer,
+            );
+        }
+
+        random_provider.distribute_by_key_with_buffer(
+            visited.available_neighbors(),
+            UnitInterval,
+            |key| self.probability_matrix[node][*key],
+            buffer,
+        )
     }
 
     #[inline]
-    fn spread_pheromone(&mut self, population: &Vec<Way<'a>>) {
-        const MIN_PHEROMONE_VALUE: f64 = 1e-5;
-
-        for row in &mut self.pheromone_matrix {
-            for element in row {
-                *element *= self.pheromone_evaporation;
-            }
+    fn spread_pheromone(&mut self, population: &Vec<Way<'a, I>>) {
+        for element in self.pheromone_matrix.as_mut_slice() {
+            *element *= self.pheromone_evaporation;
         }
 
         for ant in population {
-            let stripped_way_score = ant.score() as f64;
+            let pheromone = self.pheromone_intensity / ant.score().to_f64();
             for (from, to) in ant.way().iter_edges() {
-                self.pheromone_matrix[from][to] += self.pheromone_intensity / stripped_way_score;
+                if let Some(column) = self.edge_column(from, to) {
+                    self.pheromone_matrix[from][column] += pheromone;
+                }
+                if self.symmetry == Symmetry::Symmetric && from != to {
+                    if let Some(column) = self.edge_column(to, from) {
+                        self.pheromone_matrix[to][column] += pheromone;
+                    }
+                }
             }
         }
 
-        for row in &mut self.pheromone_matrix {
-            for element in row {
-                if *element < MIN_PHEROMONE_VALUE {
-                    *element = MIN_PHEROMONE_VALUE;
-                }
+        for element in self.pheromone_matrix.as_mut_slice() {
+            if *element < self.min_pheromone {
+                *element = self.min_pheromone;
             }
         }
     }
 
     #[inline]
-    fn global_best_way(&mut self, candidate: Way<'a>) -> Way<'a> {
+    fn global_best_way(&mut self, candidate: Way<'a, I>) -> Way<'a, I> {
+        if self.best_way.as_ref().is_none_or(|way| candidate < *way) {
+            self.stagnant_iterations = 0;
+        } else {
+            self.stagnant_iterations += 1;
+        }
+
         match self.best_way.as_mut() {
             Some(way) if candidate <= *way => {
                 *way = candidate.clone();
@@ -149,14 +461,35 @@ impl<'a> AlgorithmState<'a> {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum InitialPheromone {
+    Constant(f64),
+    Matrix(Matrix<f64>),
+    TourLength,
+}
+
+impl Default for InitialPheromone {
+    fn default() -> Self {
+        InitialPheromone::Constant(1.0)
+    }
+}
+
 pub struct AntQSolver {
-    max_iteration: u32,
+    termination: Termination,
     population_size: usize,
     random_seed: Option<u64>,
     pheromone_importance: f64,
     destination_importance: f64,
     pheromone_intensity: f64,
     pheromone_evaporation: f64,
+    candidate_lists: Option<(CandidateStrategy, usize)>,
+    threads_count: usize,
+    rng_algorithm: RngAlgorithm,
+    checkpoints: Option<(PathBuf, u32)>,
+    resume_from: Option<PathBuf>,
+    initial_way: Option<Vec<usize>>,
+    initial_pheromone: InitialPheromone,
+    restarts: Option<(RestartCriterion, RestartStrategy)>,
 }
 
 impl AntQSolver {
@@ -170,56 +503,354 @@ impl AntQSolver {
         pheromone_evaporation: f64,
     ) -> Self {
         Self {
-            max_iteration,
+            termination: Termination::MaxIterations(max_iteration),
             population_size,
             random_seed,
             pheromone_importance,
             destination_importance,
             pheromone_intensity,
             pheromone_evaporation,
+            candidate_lists: None,
+            threads_count: 1,
+            rng_algorithm: RngAlgorithm::default(),
+            checkpoints: None,
+            resume_from: None,
+            initial_way: None,
+            initial_pheromone: InitialPheromone::default(),
+            restarts: None,
         }
     }
+
+    pub fn with_candidate_lists(mut self, strategy: CandidateStrategy, size: usize) -> Self {
+        self.candidate_lists = Some((strategy, size));
+        self
+    }
+
+    pub fn with_threads_count(mut self, threads_count: usize) -> Self {
+        self.threads_count = threads_count;
+        self
+    }
+
+    pub fn with_termination(mut self, termination: Termination) -> Self {
+        self.termination = self.termination.or(termination);
+        self
+    }
+
+    pub fn with_rng_algorithm(mut self, rng_algorithm: RngAlgorithm) -> Self {
+        self.rng_algorithm = rng_algorithm;
+        self
+    }
+
+    pub fn with_checkpoints(mut self, path: impl Into<PathBuf>, interval: u32) -> Self {
+        self.checkpoints = Some((path.into(), interval.max(1)));
+        self
+    }
+
+    pub fn with_resume_from(mut self, path: impl Into<PathBuf>) -> Self {
+        self.resume_from = Some(path.into());
+        self
+    }
+
+    pub fn with_initial_way(mut self, way: Vec<usize>) -> Self {
+        self.initial_way = Some(way);
+        self
+    }
+
+    pub fn with_initial_pheromone(mut self, initial_pheromone: InitialPheromone) -> Self {
+        self.initial_pheromone = initial_pheromone;
+        self
+    }
+
+    pub fn with_restarts(mut self, criterion: RestartCriterion, strategy: RestartStrategy) -> Self {
+        self.restarts = Some((criterion, strategy));
+        self
+    }
+}
+
+impl<I: Instance> Solver<I> for AntQSolver {
+    fn solve<'a>(&self, instance: &'a I) -> Way<'a, I> {
+        self.try_solve(instance).unwrap()
+    }
+
+    fn try_solve<'a>(&self, instance: &'a I) -> Result<Way<'a, I>, SolveError> {
+        self.try_solve_with_observer(instance, &mut ())
+            .map(Solution::into_way)
+    }
+
+    fn try_solve_with_observer<'a>(
+        &self,
+        instance: &'a I,
+        observer: &mut dyn Observer<ScoreOf<I>>,
+    ) -> Result<Solution<'a, I>, SolveError> {
+        let state = self.iterations(instance)?;
+
+        self.run(state, observer)
+    }
+
+    fn try_solve_from<'a>(
+        &self,
+        instance: &'a I,
+        initial_way: Way<'a, I>,
+    ) -> Result<Way<'a, I>, SolveError> {
+        let state = self.iterations_from(instance, Some(initial_way))?;
+
+        self.run(state, &mut ()).map(Solution::into_way)
+    }
 }
 
-impl Solver for AntQSolver {
-    fn solve<'a>(&self, adj_matrix: &'a AdjMatrix<u32>) -> Way<'a> {
-        const PHEROMONE_INIT_STATE: f64 = 1.0;
+impl<I: Instance> IterativeSolver<I> for AntQSolver {
+    type Iterations<'a>
+        = AlgorithmState<'a, I>
+    where
+        I: 'a;
 
-        let nodes_count = adj_matrix.len();
-        let reverse_distance_matrix = AntQSolver::build_reverse_distance_matrix(adj_matrix);
+    fn iterations<'a>(&self, instance: &'a I) -> Result<AlgorithmState<'a, I>, SolveError> {
+        let initial_way = match &self.initial_way {
+            Some(way) => {
+                Some(Way::try_new(instance, way.clone()).map_err(SolveError::InvalidInitialWay)?)
+            }
+            None => None,
+        };
 
-        let state = AlgorithmState {
-            adj_matrix,
-            reverse_distance_matrix,
-            random_provider: random_provider(self.random_seed),
-            max_iteration: self.max_iteration,
+        self.iterations_from(instance, initial_way)
+    }
+}
+
+impl AntQSolver {
+    fn iterations_from<'a, I: Instance>(
+        &self,
+        instance: &'a I,
+        initial_way: Option<Way<'a, I>>,
+    ) -> Result<AlgorithmState<'a, I>, SolveError> {
+        if let Some(way) = &initial_way {
+            way.validate().map_err(SolveError::InvalidInitialWay)?;
+        }
+
+        let uses_checkpoints = self.checkpoints.is_some() || self.resume_from.is_some();
+        if uses_checkpoints && self.rng_algorithm == RngAlgorithm::Std {
+            return Err(SolveError::Checkpoint(
+                CheckpointError::UnsupportedRngAlgorithm(self.rng_algorithm),
+            ));
+        }
+
+        let candidate_lists = self
+            .candidate_lists
+            .map(|(strategy, size)| CandidateLists::new(instance, strategy, size));
+        let pheromone_matrix = self.initial_pheromone_matrix(
+            instance,
+            initial_way.as_ref(),
+            candidate_lists.as_ref(),
+        )?;
+        let mut state = self.build_state(instance, candidate_lists, pheromone_matrix)?;
+        state.best_way = initial_way;
+
+        if let Some(path) = &self.resume_from {
+            let checkpoint = Checkpoint::load(path).map_err(SolveError::Checkpoint)?;
+            if checkpoint.parameters != self.parameters() {
+                return Err(SolveError::Checkpoint(CheckpointError::ParametersMismatch));
+            }
+            state.restore(checkpoint).map_err(SolveError::Checkpoint)?;
+        }
+
+        Ok(state)
+    }
+
+    fn run<'a, I: Instance>(
+        &self,
+        mut state: AlgorithmState<'a, I>,
+        observer: &mut dyn Observer<ScoreOf<I>>,
+    ) -> Result<Solution<'a, I>, SolveError> {
+        state.track_diversity = observer.tracks_diversity();
+
+        while state.next().is_some() {
+            if let Some(progress) = state.progress() {
+                observer.on_iteration(&progress);
+            }
+
+            if let Some(restart) = state.restart() {
+                observer.on_restart(restart);
+            }
+
+            if let Some(stop_reason) = observer.requested_stop() {
+                state.stop_reason = Some(stop_reason);
+                break;
+            }
+
+            if let Some((path, interval)) = &self.checkpoints {
+                if state.iteration.is_multiple_of(*interval) {
+                    if let Err(error) = self.save_checkpoint(&state, path) {
+                        observer.on_checkpoint_error(state.iteration, &error);
+                    }
+                }
+            }
+        }
+
+        if let Some((path, _)) = &self.checkpoints {
+            if let Err(error) = self.save_checkpoint(&state, path) {
+                observer.on_checkpoint_error(state.iteration, &error);
+            }
+        }
+
+        let stop_reason = state.stop_reason.unwrap_or(StopReason::Completed);
+
+        Ok(Solution::new(state.best_way.unwrap(), stop_reason))
+    }
+
+    fn parameters(&self) -> Parameters {
+        Parameters {
             population_size: self.population_size,
             pheromone_importance: self.pheromone_importance,
             destination_importance: self.destination_importance,
             pheromone_intensity: self.pheromone_intensity,
             pheromone_evaporation: self.pheromone_evaporation,
+            candidate_lists: self.candidate_lists,
+            rng_algorithm: self.rng_algorithm,
+            initial_pheromone: self.initial_pheromone.clone(),
+            restarts: self.restarts.clone(),
+        }
+    }
+
+    fn save_checkpoint<I: Instance>(
+        &self,
+        state: &AlgorithmState<'_, I>,
+        path: &Path,
+    ) -> Result<(), CheckpointError> {
+        state.to_checkpoint(self.parameters()).save(path)
+    }
+
+    fn initial_pheromone_matrix<I: Instance>(
+        &self,
+        instance: &I,
+        initial_way: Option<&Way<'_, I>>,
+        candidate_lists: Option<&CandidateLists>,
+    ) -> Result<Matrix<f64>, SolveError> {
+        let nodes_count = instance.nodes_count();
+
+        match &self.initial_pheromone {
+            InitialPheromone::Constant(value) => {
+                edge_matrix(nodes_count, candidate_lists, |_, _| *value)
+            }
+            InitialPheromone::Matrix(matrix) => {
+                if matrix.rows_count() != nodes_count || matrix.columns_count() != nodes_count {
+                    return Err(SolveError::PheromoneMatrixSizeMismatch {
+                        expected: nodes_count,
+                        actual: matrix.rows_count().max(matrix.columns_count()),
+                    });
+                }
+
+                edge_matrix(nodes_count, candidate_lists, |from, to| matrix[from][to])
+            }
+            InitialPheromone::TourLength => {
+                let tour_length = match initial_way {
+                    Some(way) => way.score().to_f64(),
+                    None => GreedySolver {}.solve(instance).score().to_f64(),
+                };
+                let value = 1.0 / (nodes_count as f64 * tour_length);
+
+                edge_matrix(nodes_count, candidate_lists, |_, _| value)
+            }
+        }
+    }
+
+    fn build_state<'a, I: Instance>(
+        &self,
+        instance: &'a I,
+        candidate_lists: Option<CandidateLists>,
+        pheromone_matrix: Matrix<f64>,
+    ) -> Result<AlgorithmState<'a, I>, SolveError> {
+        const MIN_PHEROMONE_VALUE: f64 = 1e-5;
+        const MIN_PHEROMONE_RATIO: f64 = 1e-3;
+
+        let nodes_count = instance.nodes_count();
+        let heuristic_matrix = edge_matrix(nodes_count, candidate_lists.as_ref(), |from, to| {
+            power(
+                1.0 / instance.distance(from, to).to_f64(),
+                self.destination_importance,
+            )
+        })?;
+        let probability_matrix = edge_matrix(nodes_count, candidate_lists.as_ref(), |_, _| 0.0)?;
+
+        let mut state = AlgorithmState {
+            instance,
+            heuristic_matrix,
+            symmetry: if instance.is_symmetric() {
+                Symmetry::Symmetric
+            } else {
+                Symmetry::Asymmetric
+            },
+            candidate_lists,
+            rng_algorithm: self.rng_algorithm,
+            random_provider: random_provider(self.rng_algorithm, self.random_seed),
+            termination: self.termination.clone(),
+            population_size: self.population_size,
+            threads_count: self.threads_count,
+            pheromone_importance: self.pheromone_importance,
+            destination_importance: self.destination_importance,
+            pheromone_intensity: self.pheromone_intensity,
+            pheromone_evaporation: self.pheromone_evaporation,
             iteration: 0,
-            pheromone_matrix: vec![vec![PHEROMONE_INIT_STATE; nodes_count]; nodes_count],
-            probability_matrix: vec![vec![0.0; nodes_count]; nodes_count],
+            initial_pheromone_matrix: self.restarts.as_ref().map(|_| pheromone_matrix.clone()),
+            pheromone_matrix,
+            default_pheromone: MIN_PHEROMONE_VALUE,
+            min_pheromone: MIN_PHEROMONE_VALUE,
+            probability_matrix,
             best_way: None,
+            start: Instant::now(),
+            evaluations: 0,
+            stagnant_iterations: 0,
+            stop_reason: None,
+            restarts: self.restarts.clone(),
+            restarts_count: 0,
+            last_restart_iteration: 0,
+            restart: None,
+            track_diversity: false,
+            iteration_best_way: None,
+            diversity: None,
         };
 
-        state.last().unwrap()
+        let min_initial_pheromone = (0..nodes_count)
+            .flat_map(|node| state.neighbor_pheromones(node))
+            .filter(|pheromone| *pheromone > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        if min_initial_pheromone.is_finite() {
+            state.default_pheromone = min_initial_pheromone;
+            state.min_pheromone =
+                MIN_PHEROMONE_VALUE.min(min_initial_pheromone * MIN_PHEROMONE_RATIO);
+        }
+
+        Ok(state)
     }
 }
 
-impl AntQSolver {
-    #[inline]
-    fn build_reverse_distance_matrix(adj_matrix: &AdjMatrix<u32>) -> Vec<Vec<f64>> {
-        let nodes_count = adj_matrix.len();
-        let mut reverse_distance_matrix = vec![vec![0.0; nodes_count]; nodes_count];
-
-        for row in 0..nodes_count {
-            for column in 0..nodes_count {
-                reverse_distance_matrix[row][column] = 1.0 / f64::from(adj_matrix[row][column]);
-            }
+fn edge_matrix<F: Fn(usize, usize) -> f64>(
+    nodes_count: usize,
+    candidate_lists: Option<&CandidateLists>,
+    value: F,
+) -> Result<Matrix<f64>, SolveError> {
+    let matrix = match candidate_lists {
+        Some(candidate_lists) => {
+            Matrix::try_from_fn(nodes_count, candidate_lists.max_size(), |node, column| {
+                candidate_lists
+                    .candidates(node)
+                    .get(column)
+                    .map_or(0.0, |candidate| value(node, *candidate))
+            })
         }
+        None => Matrix::try_from_fn(nodes_count, nodes_count, value),
+    };
+
+    matrix.map_err(|_| SolveError::InstanceTooLarge { nodes_count })
+}
 
-        reverse_distance_matrix
+#[inline]
+fn power(value: f64, exponent: f64) -> f64 {
+    if exponent == 1.0 {
+        value
+    } else if exponent == 2.0 {
+        value * value
+    } else if exponent.fract() == 0.0 && exponent.abs() <= f64::from(i32::MAX) {
+        value.powi(exponent as i32)
+    } else {
+        value.powf(exponent)
     }
 }
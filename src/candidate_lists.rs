@@ -0,0 +1,225 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{instance::Instance, weight::Weight};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CandidateStrategy {
+    Nearest,
+    Quadrant,
+    AlphaNearness,
+}
+
+pub struct CandidateLists {
+    candidates: Vec<Vec<usize>>,
+}
+
+impl CandidateLists {
+    pub fn new<I: Instance>(instance: &I, strategy: CandidateStrategy, size: usize) -> Self {
+        let candidates = match strategy {
+            CandidateStrategy::Nearest => CandidateLists::nearest(instance, size),
+            CandidateStrategy::Quadrant => CandidateLists::quadrant(instance, size),
+            CandidateStrategy::AlphaNearness => CandidateLists::alpha_nearness(instance, size),
+        };
+
+        Self { candidates }
+    }
+
+    #[inline]
+    pub fn candidates(&self, node: usize) -> &[usize] {
+        &self.candidates[node]
+    }
+
+    #[inline]
+    pub fn position(&self, node: usize, candidate: usize) -> Option<usize> {
+        self.candidates[node]
+            .iter()
+            .position(|element| *element == candidate)
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.candidates.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    fn nearest<I: Instance>(instance: &I, size: usize) -> Vec<Vec<usize>> {
+        let nodes_count = instance.nodes_count();
+
+        (0..nodes_count)
+            .map(|node| {
+                let neighbors = (0..nodes_count).filter(|neighbor| *neighbor != node);
+                CandidateLists::closest(neighbors, size, |neighbor| {
+                    instance.distance(node, neighbor).to_f64()
+                })
+            })
+            .collect()
+    }
+
+    fn quadrant<I: Instance>(instance: &I, size: usize) -> Vec<Vec<usize>> {
+        const QUADRANTS_COUNT: usize = 4;
+
+        let nodes_count = instance.nodes_count();
+        if (0..nodes_count).any(|node| instance.node_coordinates(node).is_none()) {
+            return CandidateLists::nearest(instance, size);
+        }
+
+        let quadrant_size = size / QUADRANTS_COUNT;
+
+        (0..nodes_count)
+            .map(|node| {
+                let (x, y) = instance.node_coordinates(node).unwrap();
+                let distance = |neighbor: usize| instance.distance(node, neighbor).to_f64();
+
+                let mut candidates = Vec::with_capacity(size);
+                for quadrant in 0..QUADRANTS_COUNT {
+                    let neighbors = (0..nodes_count).filter(|neighbor| {
+                        let (neighbor_x, neighbor_y) =
+                            instance.node_coordinates(*neighbor).unwrap();
+                        let neighbor_quadrant = match (neighbor_x >= x, neighbor_y >= y) {
+                            (true, true) => 0,
+                            (false, true) => 1,
+                            (false, false) => 2,
+                            (true, false) => 3,
+                        };
+
+                        *neighbor != node && neighbor_quadrant == quadrant
+                    });
+
+                    candidates.extend(CandidateLists::closest(neighbors, quadrant_size, distance));
+                }
+
+                let remaining = size.saturating_sub(candidates.len());
+                let neighbors = (0..nodes_count)
+                    .filter(|neighbor| *neighbor != node && !candidates.contains(neighbor));
+                let mut rest = CandidateLists::closest(neighbors, remaining, distance);
+                candidates.append(&mut rest);
+
+                candidates.sort_by(|first, second| distance(*first).total_cmp(&distance(*second)));
+                candidates
+            })
+            .collect()
+    }
+
+    fn alpha_nearness<I: Instance>(instance: &I, size: usize) -> Vec<Vec<usize>> {
+        let nodes_count = instance.nodes_count();
+        let distance = |from: usize, to: usize| {
+            let forward = instance.distance(from, to).to_f64();
+            let backward = instance.distance(to, from).to_f64();
+
+            forward.min(backward)
+        };
+
+        let tree = CandidateLists::minimum_spanning_tree(nodes_count, distance);
+
+        (0..nodes_count)
+            .map(|node| {
+                let beta = CandidateLists::max_edge_on_tree_paths(&tree, node, distance);
+                let neighbors = (0..nodes_count).filter(|neighbor| *neighbor != node);
+
+                let mut candidates = CandidateLists::closest(neighbors, size, |neighbor| {
+                    distance(node, neighbor) - beta[neighbor]
+                });
+                candidates.sort_by(|first, second| {
+                    let first_alpha = distance(node, *first) - beta[*first];
+                    let second_alpha = distance(node, *second) - beta[*second];
+
+                    first_alpha
+                        .total_cmp(&second_alpha)
+                        .then(distance(node, *first).total_cmp(&distance(node, *second)))
+                });
+
+                candidates
+            })
+            .collect()
+    }
+
+    fn closest<N: Iterator<Item = usize>, F: Fn(usize) -> f64>(
+        neighbors: N,
+        size: usize,
+        key: F,
+    ) -> Vec<usize> {
+        let mut neighbors: Vec<(f64, usize)> = neighbors
+            .map(|neighbor| (key(neighbor), neighbor))
+            .collect();
+
+        if size < neighbors.len() {
+            if size == 0 {
+                return Vec::new();
+            }
+
+            neighbors
+                .select_nth_unstable_by(size - 1, |first, second| first.0.total_cmp(&second.0));
+            neighbors.truncate(size);
+        }
+        neighbors.sort_by(|first, second| first.0.total_cmp(&second.0));
+
+        let mut closest: Vec<usize> = neighbors
+            .into_iter()
+            .map(|(_, neighbor)| neighbor)
+            .collect();
+        closest.shrink_to_fit();
+
+        closest
+    }
+
+    fn minimum_spanning_tree<F: Fn(usize, usize) -> f64>(
+        nodes_count: usize,
+        distance: F,
+    ) -> Vec<Vec<usize>> {
+        const ROOT_NODE: usize = 0;
+
+        let mut tree = vec![Vec::new(); nodes_count];
+        if nodes_count == 0 {
+            return tree;
+        }
+
+        let mut in_tree = vec![false; nodes_count];
+        let mut node_distance = vec![f64::INFINITY; nodes_count];
+        let mut parent = vec![ROOT_NODE; nodes_count];
+        node_distance[ROOT_NODE] = 0.0;
+
+        for _ in 0..nodes_count {
+            let node = (0..nodes_count)
+                .filter(|node| !in_tree[*node])
+                .min_by(|first, second| node_distance[*first].total_cmp(&node_distance[*second]))
+                .unwrap();
+
+            in_tree[node] = true;
+            if node != ROOT_NODE {
+                tree[node].push(parent[node]);
+                tree[parent[node]].push(node);
+            }
+
+            for neighbor in (0..nodes_count).filter(|neighbor| !in_tree[*neighbor]) {
+                let neighbor_distance = distance(node, neighbor);
+                if neighbor_distance < node_distance[neighbor] {
+                    node_distance[neighbor] = neighbor_distance;
+                    parent[neighbor] = node;
+                }
+            }
+        }
+
+        tree
+    }
+
+    fn max_edge_on_tree_paths<F: Fn(usize, usize) -> f64>(
+        tree: &[Vec<usize>],
+        root: usize,
+        distance: F,
+    ) -> Vec<f64> {
+        let mut beta = vec![0.0f64; tree.len()];
+        let mut visited = vec![false; tree.len()];
+        let mut stack = vec![root];
+        visited[root] = true;
+
+        while let Some(node) = stack.pop() {
+            for neighbor in &tree[node] {
+                if !visited[*neighbor] {
+                    visited[*neighbor] = true;
+                    beta[*neighbor] = beta[node].max(distance(node, *neighbor));
+                    stack.push(*neighbor);
+                }
+            }
+        }
+
+        beta
+    }
+}
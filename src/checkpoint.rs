@@ -0,0 +1,96 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ant_q_solver::InitialPheromone,
+    candidate_lists::CandidateStrategy,
+    convergence::{RestartCriterion, RestartStrategy},
+    matrix::Matrix,
+    models::WayError,
+    rand_utils::{RandomProvider, RngAlgorithm},
+};
+
+#[derive(Debug)]
+pub enum CheckpointError {
+    Io(io::Error),
+    Serialization(serde_json::Error),
+    NodesCountMismatch { expected: usize, actual: usize },
+    InvalidBestWay(WayError),
+    ParametersMismatch,
+    UnsupportedRngAlgorithm(RngAlgorithm),
+}
+
+impl From<io::Error> for CheckpointError {
+    fn from(error: io::Error) -> Self {
+        CheckpointError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for CheckpointError {
+    fn from(error: serde_json::Error) -> Self {
+        CheckpointError::Serialization(error)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Parameters {
+    pub population_size: usize,
+    pub pheromone_importance: f64,
+    pub destination_importance: f64,
+    pub pheromone_intensity: f64,
+    pub pheromone_evaporation: f64,
+    pub candidate_lists: Option<(CandidateStrategy, usize)>,
+    pub rng_algorithm: RngAlgorithm,
+    #[serde(default)]
+    pub initial_pheromone: InitialPheromone,
+    #[serde(default)]
+    pub restarts: Option<(RestartCriterion, RestartStrategy)>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub parameters: Parameters,
+    pub iteration: u32,
+    pub evaluations: u64,
+    pub stagnant_iterations: u32,
+    #[serde(default)]
+    pub restarts: u32,
+    #[serde(default)]
+    pub last_restart_iteration: u32,
+    pub elapsed: Duration,
+    pub pheromone_matrix: Matrix<f64>,
+    pub best_way: Option<Vec<usize>>,
+    pub random_provider: RandomProvider,
+}
+
+impl Checkpoint {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, CheckpointError> {
+        let reader = BufReader::new(File::open(path)?);
+
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), CheckpointError> {
+        let path = path.as_ref();
+        let temporary_path = path.with_extension("tmp");
+
+        let mut writer = BufWriter::new(File::create(&temporary_path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        drop(writer);
+
+        fs::rename(temporary_path, path)?;
+
+        Ok(())
+    }
+
+    pub fn nodes_count(&self) -> usize {
+        self.pheromone_matrix.len()
+    }
+}
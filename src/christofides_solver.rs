@@ -0,0 +1,160 @@
+use crate::{
+    instance::Instance,
+    instance_analysis::{
+        find_asymmetric_pairs, find_triangle_violations, Symmetry, TriangleViolation,
+    },
+    models::{SolveError, Solver, Way},
+    perfect_matching::{exact_perfect_matching, greedy_perfect_matching},
+    weight::Weight,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchingAlgorithm {
+    Exact,
+    Greedy,
+}
+
+pub struct ChristofidesSolver {
+    matching_algorithm: MatchingAlgorithm,
+}
+
+impl ChristofidesSolver {
+    pub fn new(matching_algorithm: MatchingAlgorithm) -> Self {
+        Self { matching_algorithm }
+    }
+}
+
+impl<I: Instance> Solver<I> for ChristofidesSolver {
+    fn solve<'a>(&self, instance: &'a I) -> Way<'a, I> {
+        self.try_solve(instance).unwrap()
+    }
+
+    fn try_solve<'a>(&self, instance: &'a I) -> Result<Way<'a, I>, SolveError> {
+        const START_NODE: usize = 0;
+
+        if !instance.is_symmetric() {
+            if let Some((row, column)) = find_asymmetric_pairs(instance).next() {
+                return Err(SolveError::MatrixNotSymmetric { row, column });
+            }
+        }
+        if !instance.is_metric() {
+            let violation = find_triangle_violations(instance, Symmetry::Symmetric).next();
+            if let Some(TriangleViolation { from, via, to, .. }) = violation {
+                return Err(SolveError::TriangleInequalityViolated { from, via, to });
+            }
+        }
+
+        let nodes_count = instance.nodes_count();
+        if nodes_count == 0 {
+            return Ok(Way::new(instance, Vec::new()));
+        }
+
+        let mut edges = ChristofidesSolver::minimum_spanning_tree(instance);
+
+        let mut degrees = vec![0usize; nodes_count];
+        for (from, to) in &edges {
+            degrees[*from] += 1;
+            degrees[*to] += 1;
+        }
+
+        let odd_nodes: Vec<usize> = (0..nodes_count)
+            .filter(|node| degrees[*node] % 2 == 1)
+            .collect();
+
+        let matching = match self.matching_algorithm {
+            MatchingAlgorithm::Exact => exact_perfect_matching(instance, &odd_nodes),
+            MatchingAlgorithm::Greedy => greedy_perfect_matching(instance, &odd_nodes),
+        };
+        edges.extend(matching);
+
+        let circuit = ChristofidesSolver::eulerian_circuit(nodes_count, &edges, START_NODE);
+
+        let mut visited = vec![false; nodes_count];
+        let mut way: Vec<usize> = Vec::with_capacity(nodes_count + 1);
+        for node in circuit {
+            if !visited[node] {
+                visited[node] = true;
+                way.push(node);
+            }
+        }
+        way.push(START_NODE);
+
+        Ok(Way::new(instance, way))
+    }
+}
+
+impl ChristofidesSolver {
+    #[inline]
+    fn minimum_spanning_tree<I: Instance>(instance: &I) -> Vec<(usize, usize)> {
+        const ROOT_NODE: usize = 0;
+        let nodes_count = instance.nodes_count();
+
+        let mut in_tree = vec![false; nodes_count];
+        let mut distance = vec![I::Weight::MAX; nodes_count];
+        let mut parent = vec![ROOT_NODE; nodes_count];
+        let mut edges = Vec::with_capacity(nodes_count.saturating_sub(1));
+
+        distance[ROOT_NODE] = I::Weight::default();
+        for _ in 0..nodes_count {
+            let node = match in_tree
+                .iter()
+                .enumerate()
+                .filter(|(_, in_tree)| !**in_tree)
+                .min_by(|(first, _), (second, _)| distance[*first].total_cmp(&distance[*second]))
+            {
+                Some((node, _)) => node,
+                None => break,
+            };
+
+            in_tree[node] = true;
+            if node != ROOT_NODE {
+                edges.push((parent[node], node));
+            }
+
+            for neighbor in 0..nodes_count {
+                let neighbor_distance = instance.distance(node, neighbor);
+                if !in_tree[neighbor] && neighbor_distance < distance[neighbor] {
+                    distance[neighbor] = neighbor_distance;
+                    parent[neighbor] = node;
+                }
+            }
+        }
+
+        edges
+    }
+
+    #[inline]
+    fn eulerian_circuit(
+        nodes_count: usize,
+        edges: &[(usize, usize)],
+        start_node: usize,
+    ) -> Vec<usize> {
+        let mut incident_edges: Vec<Vec<usize>> = vec![Vec::new(); nodes_count];
+        for (index, (from, to)) in edges.iter().enumerate() {
+            incident_edges[*from].push(index);
+            incident_edges[*to].push(index);
+        }
+
+        let mut used = vec![false; edges.len()];
+        let mut stack = vec![start_node];
+        let mut circuit = Vec::with_capacity(edges.len() + 1);
+
+        while let Some(&node) = stack.last() {
+            match incident_edges[node].pop() {
+                Some(index) if used[index] => {}
+                Some(index) => {
+                    used[index] = true;
+
+                    let (from, to) = edges[index];
+                    stack.push(if from == node { to } else { from });
+                }
+                None => {
+                    circuit.push(node);
+                    stack.pop();
+                }
+            }
+        }
+
+        circuit
+    }
+}
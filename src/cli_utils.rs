@@ -1,23 +1,37 @@
 use std::{
+    collections::BTreeMap,
     fs::File,
     io::{stdin, stdout, BufRead, BufReader, Read, Write},
-    u32,
+    path::{Path, PathBuf},
+    str::FromStr,
 };
 
-use rand::Rng;
-
-use crate::{
-    ant_q_solver::AntQSolver,
-    greedy_solver::GreedySolver,
-    models::{AdjMatrix, Solver},
-    rand_utils::random_provider,
+use serde::de::DeserializeOwned;
+
+use cea::{
+    config::{
+        ConfigError, CoordinateMetric, InstanceConfig, OutputConfig, ParameterValue, RunConfig,
+        SolverConfig,
+    },
+    instance::{CoordinateInstance, Instance},
+    models::AdjMatrix,
+    rand_utils::{random_provider, RngAlgorithm, RngDistributionExt},
+    registry::{BoxedSolver, BuildContext, RegistryError, SolverRegistry},
+    weight::Weight,
 };
 
+#[allow(dead_code)]
 #[derive(Debug)]
 pub enum ReadAlgorithmError {
     UnknownAlgorithm,
-    UnableToParseUnt32,
-    UnableToParseUsize,
+    UnknownSource,
+    UnknownMetric,
+    MissingFlagValue(String),
+    UnexpectedArgument(String),
+    InvalidFlagValue { flag: String, value: String },
+    UnableToParseU64,
+    Config(ConfigError),
+    Registry(RegistryError),
 }
 
 #[derive(Debug)]
@@ -26,141 +40,187 @@ pub enum ReadAdjMatrixError {
     RowsAndColumnsCountMismatch,
     DiagonalElementsMustBeZero,
     NonDiagonalElementsMustBeGreaterThanZero,
-    UnableToParseUnt32,
-    UnknownSource,
 }
 
-pub fn solver(random_seed: Option<u64>) -> Result<Box<dyn Solver>, ReadAlgorithmError> {
-    let prompt = "Choose algorithm:
-1. Greedy algorithm
-2. Ant-Q algorithm
-Enter value: ";
-
-    if let Some(option) = choose_option(prompt, 1, 2) {
-        match option {
-            1 => Ok(Box::new(GreedySolver {})),
-            2 => match build_ant_q_solver(random_seed) {
-                Ok(solver) => Ok(Box::new(solver)),
-                Err(msg) => Err(msg),
-            },
-            _ => Err(ReadAlgorithmError::UnknownAlgorithm),
-        }
-    } else {
-        Err(ReadAlgorithmError::UnknownAlgorithm)
-    }
+pub enum AnyInstance {
+    IntegerMatrix(AdjMatrix<u32>),
+    RealMatrix(AdjMatrix<f64>),
+    IntegerCoordinates(CoordinateInstance<u32>),
+    RealCoordinates(CoordinateInstance<f64>),
 }
 
-pub fn build_ant_q_solver(random_seed: Option<u64>) -> Result<AntQSolver, ReadAlgorithmError> {
-    print!("Enter max iterations: ");
-    stdout().flush().unwrap();
+pub enum Command {
+    Help,
+    Run(Box<RunConfig>),
+}
 
-    let mut max_iterations = String::new();
-    stdin().read_line(&mut max_iterations).unwrap();
+pub fn command<I: Instance>(
+    registry: &SolverRegistry<I>,
+    mut arguments: impl Iterator<Item = String>,
+) -> Result<Command, ReadAlgorithmError> {
+    const INSTANCE_FLAG_PREFIX: &str = "instance-";
 
-    let max_iterations: u32 = match max_iterations.trim().parse() {
-        Ok(number) => number,
-        _ => return Err(ReadAlgorithmError::UnableToParseUnt32),
-    };
+    let mut config_path = None;
+    let mut solver_name = None;
+    let mut instance_flags = toml::Table::new();
+    let mut overrides = Vec::new();
 
-    print!("Enter population size: ");
-    stdout().flush().unwrap();
+    while let Some(argument) = arguments.next() {
+        if argument == "--help" || argument == "-h" {
+            return Ok(Command::Help);
+        }
 
-    let mut population_size = String::new();
-    stdin().read_line(&mut population_size).unwrap();
+        let Some(name) = argument.strip_prefix("--") else {
+            if config_path.is_some() {
+                return Err(ReadAlgorithmError::UnexpectedArgument(argument));
+            }
+            config_path = Some(argument);
+            continue;
+        };
 
-    let population_size: usize = match population_size.trim().parse() {
-        Ok(number) => number,
-        _ => return Err(ReadAlgorithmError::UnableToParseUsize),
-    };
+        let Some(value) = arguments.next() else {
+            return Err(ReadAlgorithmError::MissingFlagValue(name.to_string()));
+        };
 
-    let solver = AntQSolver::new(
-        max_iterations,
-        population_size,
-        random_seed,
-        1.0,
-        2.0,
-        1.0,
-        0.1,
-    );
+        if name == "config" {
+            config_path = Some(value);
+        } else if name == "solver" {
+            solver_name = Some(value);
+        } else if let Some(key) = name.strip_prefix(INSTANCE_FLAG_PREFIX) {
+            instance_flags.insert(key.to_string(), toml_value(&value));
+        } else {
+            overrides.push((name.to_string(), value));
+        }
+    }
 
-    Ok(solver)
-}
+    let instance = if instance_flags.is_empty() {
+        None
+    } else {
+        Some(
+            toml::Value::Table(instance_flags)
+                .try_into::<InstanceConfig>()
+                .map_err(|error| ReadAlgorithmError::Config(error.into()))?,
+        )
+    };
 
-pub fn adj_matrix(random_seed: Option<u64>) -> Result<AdjMatrix<u32>, ReadAdjMatrixError> {
-    let prompt = "Choose matrix source:
-1. From file
-2. Random
-Enter value: ";
+    let mut config = match config_path {
+        Some(config_path) => RunConfig::load(config_path).map_err(ReadAlgorithmError::Config)?,
+        None => RunConfig {
+            seed: None,
+            rng_algorithm: RngAlgorithm::default(),
+            instance: match &instance {
+                Some(instance) => instance.clone(),
+                None => choose_instance()?,
+            },
+            solver: match &solver_name {
+                Some(name) => SolverConfig {
+                    name: name.clone(),
+                    parameters: BTreeMap::new(),
+                },
+                None => choose_solver(registry)?,
+            },
+            termination: BTreeMap::new(),
+            output: OutputConfig::default(),
+        },
+    };
 
-    if let Some(option) = choose_option(prompt, 1, 2) {
-        return match option {
-            1 => {
-                print!("Enter path: ");
-                stdout().flush().unwrap();
+    if let Some(instance) = instance {
+        config.instance = instance;
+    }
 
-                let mut path = String::new();
-                stdin().read_line(&mut path).unwrap();
+    if let Some(name) = solver_name {
+        config.solver = SolverConfig {
+            name,
+            parameters: BTreeMap::new(),
+        };
+    }
 
-                adj_matrix_from_file(path.trim())
-            }
-            2 => {
-                print!("Enter rows count: ");
-                stdout().flush().unwrap();
-
-                let mut rows_count = String::new();
-                stdin().read_line(&mut rows_count).unwrap();
-
-                let rows_count: usize = match rows_count.trim().parse() {
-                    Ok(number) => number,
-                    _ => return Err(ReadAdjMatrixError::UnableToParseUnt32),
-                };
-
-                print!("Enter columns count: ");
-                stdout().flush().unwrap();
-
-                let mut columns_count = String::new();
-                stdin().read_line(&mut columns_count).unwrap();
-
-                let columns_count: usize = match columns_count.trim().parse() {
-                    Ok(number) => number,
-                    _ => return Err(ReadAdjMatrixError::UnableToParseUnt32),
-                };
-
-                print!("Enter minimum value: ");
-                stdout().flush().unwrap();
-
-                let mut min_value = String::new();
-                stdin().read_line(&mut min_value).unwrap();
-
-                let min_value: u32 = match min_value.trim().parse() {
-                    Ok(number) => number,
-                    _ => return Err(ReadAdjMatrixError::UnableToParseUnt32),
-                };
-
-                print!("Enter maximum value: ");
-                stdout().flush().unwrap();
-
-                let mut max_value = String::new();
-                stdin().read_line(&mut max_value).unwrap();
-
-                let max_value: u32 = match max_value.trim().parse() {
-                    Ok(number) => number,
-                    _ => return Err(ReadAdjMatrixError::UnableToParseUnt32),
-                };
-
-                Ok(random_adj_matrix(
-                    rows_count,
-                    columns_count,
-                    min_value,
-                    max_value,
-                    random_seed,
-                ))
+    for (name, value) in overrides {
+        match name.as_str() {
+            "seed" => match value.parse() {
+                Ok(seed) => config.seed = Some(seed),
+                _ => return Err(ReadAlgorithmError::UnableToParseU64),
+            },
+            "rng-algorithm" => config.rng_algorithm = flag_value(&name, value)?,
+            "format" => config.output.format = flag_value(&name, value)?,
+            "progress-interval" => config.output.progress_interval = flag_value(&name, value)?,
+            "progress-csv" => config.output.progress_csv = Some(PathBuf::from(value)),
+            "lower-bounds" => config.output.lower_bounds = flag_value(&name, value)?,
+            _ => {
+                let value: ParameterValue = value.parse().unwrap();
+                match config.termination.get_mut(&name) {
+                    Some(termination) => *termination = value,
+                    None => {
+                        config.solver.parameters.insert(name, value);
+                    }
+                }
             }
-            _ => Err(ReadAdjMatrixError::UnknownSource),
-        };
+        }
     }
 
-    Err(ReadAdjMatrixError::UnknownSource)
+    Ok(Command::Run(Box::new(config)))
+}
+
+pub fn usage<I: Instance>(registry: &SolverRegistry<I>) -> String {
+    format!(
+        "Usage: cea [[--config] <path>] [--solver <name>] [--<parameter> <value>]...
+
+Without a configuration file the instance is read from the --instance-* flags
+and the solver from --solver; whatever is missing is asked for interactively.
+
+Instance:
+    --instance-source <matrix|coordinates|random>
+    --instance-path <path>                   Matrix or coordinates file
+    --instance-real <bool>                   Read real-valued matrix (default: false)
+    --instance-metric <metric>               euclidean, manhattan, chebyshev, great-circle,
+                                             tsplib-euc2d, tsplib-att, tsplib-geo
+    --instance-size <integer>                Random matrix size
+    --instance-min <integer>                 Random matrix minimum weight
+    --instance-max <integer>                 Random matrix maximum weight
+
+Run:
+    --seed <integer>                         Random seed
+    --rng-algorithm <pcg64|xoshiro256-plus-plus|std>
+    --format <text|json>                     Output format (default: text)
+    --progress-interval <integer>            Iterations between progress lines (default: 10)
+    --progress-csv <path>                    Write progress to a CSV file
+    --lower-bounds <bool>                    Compute lower bounds and gap (default: true)
+
+{}",
+        registry.help()
+    )
+}
+
+pub fn solver<I: Instance>(
+    registry: &SolverRegistry<I>,
+    config: &RunConfig,
+    context: &BuildContext,
+) -> Result<BoxedSolver<I>, ReadAlgorithmError> {
+    registry
+        .build(&config.solver.name, &config.solver_arguments(), context)
+        .map_err(ReadAlgorithmError::Registry)
+}
+
+pub fn instance(
+    config: &InstanceConfig,
+    rng_algorithm: RngAlgorithm,
+    random_seed: Option<u64>,
+) -> Result<AnyInstance, ReadAdjMatrixError> {
+    match config {
+        InstanceConfig::Matrix { path, real: false } => {
+            adj_matrix_from_file(path).map(AnyInstance::IntegerMatrix)
+        }
+        InstanceConfig::Matrix { path, real: true } => {
+            adj_matrix_from_file(path).map(AnyInstance::RealMatrix)
+        }
+        InstanceConfig::Coordinates { path, metric } => {
+            let coordinates = coordinates_from_file(path)?;
+            Ok(coordinate_instance(coordinates, *metric))
+        }
+        InstanceConfig::Random { size, min, max } => Ok(AnyInstance::IntegerMatrix(
+            random_adj_matrix(*size, *size, *min, *max, rng_algorithm, random_seed),
+        )),
+    }
 }
 
 fn random_adj_matrix(
@@ -168,79 +228,257 @@ fn random_adj_matrix(
     columns_count: usize,
     min_value: u32,
     max_value: u32,
+    rng_algorithm: RngAlgorithm,
     random_seed: Option<u64>,
 ) -> AdjMatrix<u32> {
-    let mut random_provider = random_provider(random_seed);
-    let mut matrix = vec![vec![0u32; columns_count]; rows_count];
+    let mut random_provider = random_provider(rng_algorithm, random_seed);
+    let mut matrix = AdjMatrix::new(rows_count, columns_count, 0u32);
 
-    for row in &mut matrix {
+    for row in matrix.rows_mut() {
         for element in row {
-            *element = random_provider.gen_range(min_value..=max_value);
+            *element = min_value
+                + random_provider.uniform_below(u64::from(max_value - min_value) + 1) as u32;
         }
     }
 
     matrix
 }
 
-fn adj_matrix_from_file(path: &str) -> Result<AdjMatrix<u32>, ReadAdjMatrixError> {
+fn coordinate_instance(coordinates: Vec<(f64, f64)>, metric: CoordinateMetric) -> AnyInstance {
+    if metric.is_integral() {
+        AnyInstance::IntegerCoordinates(CoordinateInstance::new(coordinates, metric.metric()))
+    } else {
+        AnyInstance::RealCoordinates(CoordinateInstance::new(coordinates, metric.metric()))
+    }
+}
+
+fn coordinates_from_file(path: &Path) -> Result<Vec<(f64, f64)>, ReadAdjMatrixError> {
+    match File::open(path) {
+        Ok(mut file) => Ok(coordinates_from_reader(&mut file)),
+        _ => Err(ReadAdjMatrixError::FileNotFound),
+    }
+}
+
+fn coordinates_from_reader(reader: &mut dyn Read) -> Vec<(f64, f64)> {
+    let buf_reader = BufReader::new(reader);
+
+    buf_reader
+        .lines()
+        .map_while(Result::ok)
+        .filter_map(|line| {
+            let numbers: Result<Vec<f64>, _> = line.split_whitespace().map(str::parse).collect();
+
+            match numbers.ok()?.as_slice() {
+                [x, y] | [_, x, y] => Some((*x, *y)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+fn adj_matrix_from_file<W: Weight + FromStr>(
+    path: &Path,
+) -> Result<AdjMatrix<W>, ReadAdjMatrixError> {
     match File::open(path) {
         Ok(mut file) => adj_matrix_from_reader(&mut file),
         _ => Err(ReadAdjMatrixError::FileNotFound),
     }
 }
 
-fn adj_matrix_from_reader(reader: &mut dyn Read) -> Result<AdjMatrix<u32>, ReadAdjMatrixError> {
+fn adj_matrix_from_reader<W: Weight + FromStr>(
+    reader: &mut dyn Read,
+) -> Result<AdjMatrix<W>, ReadAdjMatrixError> {
     let buf_reader = BufReader::new(reader);
 
-    let matrix: AdjMatrix<u32> = buf_reader
+    let matrix: AdjMatrix<W> = match buf_reader
         .lines()
         .map(|line| {
             line.unwrap()
-                .trim()
                 .split_whitespace()
                 .flat_map(str::parse)
                 .collect()
         })
-        .collect();
+        .collect::<Vec<Vec<W>>>()
+        .try_into()
+    {
+        Ok(matrix) => matrix,
+        _ => return Err(ReadAdjMatrixError::RowsAndColumnsCountMismatch),
+    };
 
     let size = matrix.len();
     if size == 0 {
         return Ok(matrix);
     }
 
-    for row in 0..size {
-        for column in 0..size {
-            let element = matrix[row][column];
+    if matrix.columns_count() != size {
+        return Err(ReadAdjMatrixError::RowsAndColumnsCountMismatch);
+    }
 
-            if element == 0 && row != column {
+    for (row, elements) in matrix.rows().enumerate() {
+        for (column, &element) in elements.iter().enumerate() {
+            if element <= W::default() && row != column {
                 return Err(ReadAdjMatrixError::NonDiagonalElementsMustBeGreaterThanZero);
             }
 
-            if row == column && element != 0 {
+            if row == column && element != W::default() {
                 return Err(ReadAdjMatrixError::DiagonalElementsMustBeZero);
             }
         }
     }
 
-    if matrix.iter().any(|row| row.len() != size) {
-        return Err(ReadAdjMatrixError::RowsAndColumnsCountMismatch);
+    Ok(matrix)
+}
+
+fn toml_value(value: &str) -> toml::Value {
+    match value.parse() {
+        Ok(ParameterValue::Integer(value)) => toml::Value::Integer(value),
+        Ok(ParameterValue::Real(value)) => toml::Value::Float(value),
+        Ok(ParameterValue::Boolean(value)) => toml::Value::Boolean(value),
+        _ => toml::Value::String(value.to_string()),
     }
+}
 
-    Ok(matrix)
+fn flag_value<T: DeserializeOwned>(flag: &str, value: String) -> Result<T, ReadAlgorithmError> {
+    toml_value(&value)
+        .try_into()
+        .map_err(|_| ReadAlgorithmError::InvalidFlagValue {
+            flag: flag.to_string(),
+            value,
+        })
 }
 
-fn choose_option(prompt: &str, min_value: u32, max_value: u32) -> Option<u32> {
-    print!("{prompt}");
-    stdout().flush().unwrap();
+fn choose_solver<I: Instance>(
+    registry: &SolverRegistry<I>,
+) -> Result<SolverConfig, ReadAlgorithmError> {
+    let entries = registry.entries();
+
+    let mut prompt = String::from("Choose algorithm:\n");
+    for (index, entry) in entries.iter().enumerate() {
+        prompt.push_str(&format!("{}. {}\n", index + 1, entry.description()));
+    }
+    prompt.push_str("Enter value: ");
 
-    let mut choice = String::new();
-    stdin().read_line(&mut choice).unwrap();
+    let entry = match choose_option(&prompt, 1, entries.len() as u32) {
+        Some(option) => &entries[option as usize - 1],
+        None => return Err(ReadAlgorithmError::UnknownAlgorithm),
+    };
 
-    if let Ok(choice) = choice.trim().parse::<u32>() {
-        if choice >= min_value && choice <= max_value {
-            return Some(choice);
+    let mut parameters = BTreeMap::new();
+    if entry.parameters().is_empty() || accepts_defaults() {
+        return Ok(SolverConfig {
+            name: entry.name().to_string(),
+            parameters,
+        });
+    }
+
+    for spec in entry.parameters() {
+        let value = read_line(&format!(
+            "{} <{}> [{}]: ",
+            spec.description,
+            spec.kind,
+            spec.default.unwrap_or("none")
+        ));
+
+        if !value.is_empty() {
+            parameters.insert(
+                spec.name.to_string(),
+                ParameterValue::of_kind(spec.kind, &value),
+            );
         }
     }
 
-    None
+    Ok(SolverConfig {
+        name: entry.name().to_string(),
+        parameters,
+    })
+}
+
+fn accepts_defaults() -> bool {
+    matches!(
+        read_line("Use default parameters? [Y/n]: ")
+            .to_lowercase()
+            .as_str(),
+        "" | "y" | "yes"
+    )
+}
+
+fn choose_instance() -> Result<InstanceConfig, ReadAlgorithmError> {
+    let prompt = "Choose matrix source:
+1. From file
+2. Random
+3. From file (real-valued)
+4. From coordinates file
+Enter value: ";
+
+    match choose_option(prompt, 1, 4) {
+        Some(1) => Ok(InstanceConfig::Matrix {
+            path: PathBuf::from(read_line("Enter path: ")),
+            real: false,
+        }),
+        Some(2) => Ok(InstanceConfig::Random {
+            size: read_number("Enter size: ")?,
+            min: read_number("Enter minimum value: ")?,
+            max: read_number("Enter maximum value: ")?,
+        }),
+        Some(3) => Ok(InstanceConfig::Matrix {
+            path: PathBuf::from(read_line("Enter path: ")),
+            real: true,
+        }),
+        Some(4) => Ok(InstanceConfig::Coordinates {
+            path: PathBuf::from(read_line("Enter path: ")),
+            metric: choose_metric()?,
+        }),
+        _ => Err(ReadAlgorithmError::UnknownSource),
+    }
+}
+
+fn choose_metric() -> Result<CoordinateMetric, ReadAlgorithmError> {
+    let prompt = "Choose metric:
+1. Euclidean
+2. Manhattan
+3. Chebyshev
+4. Great-circle (latitude, longitude)
+5. TSPLIB EUC_2D (rounded Euclidean)
+6. TSPLIB ATT
+7. TSPLIB GEO
+Enter value: ";
+
+    match choose_option(prompt, 1, 7) {
+        Some(1) => Ok(CoordinateMetric::Euclidean),
+        Some(2) => Ok(CoordinateMetric::Manhattan),
+        Some(3) => Ok(CoordinateMetric::Chebyshev),
+        Some(4) => Ok(CoordinateMetric::GreatCircle),
+        Some(5) => Ok(CoordinateMetric::TsplibEuc2d),
+        Some(6) => Ok(CoordinateMetric::TsplibAtt),
+        Some(7) => Ok(CoordinateMetric::TsplibGeo),
+        _ => Err(ReadAlgorithmError::UnknownMetric),
+    }
+}
+
+fn read_line(prompt: &str) -> String {
+    print!("{prompt}");
+    stdout().flush().unwrap();
+
+    let mut line = String::new();
+    stdin().read_line(&mut line).unwrap();
+
+    line.trim().to_string()
+}
+
+fn read_number<T: FromStr>(prompt: &str) -> Result<T, ReadAlgorithmError> {
+    let value = read_line(prompt);
+
+    value
+        .parse()
+        .map_err(|_| ReadAlgorithmError::InvalidFlagValue {
+            flag: prompt.trim_end_matches(": ").to_string(),
+            value,
+        })
+}
+
+fn choose_option(prompt: &str, min_value: u32, max_value: u32) -> Option<u32> {
+    match read_line(prompt).parse::<u32>() {
+        Ok(choice) if choice >= min_value && choice <= max_value => Some(choice),
+        _ => None,
+    }
 }
@@ -0,0 +1,320 @@
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    instance::{Instance, Metric},
+    rand_utils::RngAlgorithm,
+    registry::{Arguments, ParameterKind, RegistryError, SolverRegistry},
+};
+
+const MAX_SEED: u64 = i64::MAX as u64;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    TomlDeserialization(toml::de::Error),
+    TomlSerialization(toml::ser::Error),
+    Json(serde_json::Error),
+    Registry(RegistryError),
+    InvalidRandomRange { min: u32, max: u32 },
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(error: io::Error) -> Self {
+        ConfigError::Io(error)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(error: toml::de::Error) -> Self {
+        ConfigError::TomlDeserialization(error)
+    }
+}
+
+impl From<toml::ser::Error> for ConfigError {
+    fn from(error: toml::ser::Error) -> Self {
+        ConfigError::TomlSerialization(error)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(error: serde_json::Error) -> Self {
+        ConfigError::Json(error)
+    }
+}
+
+impl From<RegistryError> for ConfigError {
+    fn from(error: RegistryError) -> Self {
+        ConfigError::Registry(error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    pub fn of<P: AsRef<Path>>(path: P) -> Self {
+        match path
+            .as_ref()
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some("json") => ConfigFormat::Json,
+            _ => ConfigFormat::Toml,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ParameterValue {
+    Integer(i64),
+    Real(f64),
+    Boolean(bool),
+    Text(String),
+}
+
+impl ParameterValue {
+    pub fn of_kind(kind: ParameterKind, value: &str) -> Self {
+        let parsed = match kind {
+            ParameterKind::Integer => value.parse().ok().map(ParameterValue::Integer),
+            ParameterKind::Real => value.parse().ok().map(ParameterValue::Real),
+            ParameterKind::Text | ParameterKind::Choice(_) => None,
+        };
+
+        parsed.unwrap_or_else(|| ParameterValue::Text(value.to_string()))
+    }
+}
+
+impl FromStr for ParameterValue {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(if let Ok(integer) = value.parse() {
+            ParameterValue::Integer(integer)
+        } else if let Ok(real) = value.parse() {
+            ParameterValue::Real(real)
+        } else if let Ok(boolean) = value.parse() {
+            ParameterValue::Boolean(boolean)
+        } else {
+            ParameterValue::Text(value.to_string())
+        })
+    }
+}
+
+impl Display for ParameterValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterValue::Integer(value) => write!(f, "{value}"),
+            ParameterValue::Real(value) => write!(f, "{value}"),
+            ParameterValue::Boolean(value) => write!(f, "{value}"),
+            ParameterValue::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CoordinateMetric {
+    Euclidean,
+    Manhattan,
+    Chebyshev,
+    GreatCircle,
+    TsplibEuc2d,
+    TsplibAtt,
+    TsplibGeo,
+}
+
+impl CoordinateMetric {
+    pub fn metric(&self) -> Metric {
+        match self {
+            CoordinateMetric::Euclidean | CoordinateMetric::TsplibEuc2d => Metric::Euclidean,
+            CoordinateMetric::Manhattan => Metric::Manhattan,
+            CoordinateMetric::Chebyshev => Metric::Chebyshev,
+            CoordinateMetric::GreatCircle => Metric::GreatCircle,
+            CoordinateMetric::TsplibAtt => Metric::TsplibAtt,
+            CoordinateMetric::TsplibGeo => Metric::TsplibGeo,
+        }
+    }
+
+    pub fn is_integral(&self) -> bool {
+        matches!(
+            self,
+            CoordinateMetric::TsplibEuc2d
+                | CoordinateMetric::TsplibAtt
+                | CoordinateMetric::TsplibGeo
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "source", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum InstanceConfig {
+    Matrix {
+        path: PathBuf,
+        #[serde(default)]
+        real: bool,
+    },
+    Coordinates {
+        path: PathBuf,
+        metric: CoordinateMetric,
+    },
+    Random {
+        size: usize,
+        min: u32,
+        max: u32,
+    },
+}
+
+impl InstanceConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        match self {
+            InstanceConfig::Random { min, max, .. } if min > max => {
+                Err(ConfigError::InvalidRandomRange {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SolverConfig {
+    pub name: String,
+    #[serde(flatten)]
+    pub parameters: BTreeMap<String, ParameterValue>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct OutputConfig {
+    pub format: OutputFormat,
+    pub progress_interval: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress_csv: Option<PathBuf>,
+    pub lower_bounds: bool,
+}
+
+impl Default for OutputConfig {
+    fn default() -> Self {
+        Self {
+            format: OutputFormat::default(),
+            progress_interval: 10,
+            progress_csv: None,
+            lower_bounds: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct RunConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub rng_algorithm: RngAlgorithm,
+    pub instance: InstanceConfig,
+    pub solver: SolverConfig,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub termination: BTreeMap<String, ParameterValue>,
+    #[serde(default)]
+    pub output: OutputConfig,
+}
+
+impl RunConfig {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+
+        match ConfigFormat::of(path) {
+            ConfigFormat::Toml => Ok(toml::from_str(&content)?),
+            ConfigFormat::Json => Ok(serde_json::from_str(&content)?),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ConfigError> {
+        let path = path.as_ref();
+
+        let content = match ConfigFormat::of(path) {
+            ConfigFormat::Toml => self.to_toml()?,
+            ConfigFormat::Json => self.to_json()?,
+        };
+        fs::write(path, content)?;
+
+        Ok(())
+    }
+
+    pub fn to_toml(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string(self)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, ConfigError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn solver_arguments(&self) -> Arguments {
+        self.solver
+            .parameters
+            .iter()
+            .chain(&self.termination)
+            .fold(Arguments::new(), |arguments, (name, value)| {
+                arguments.with(name, value.to_string())
+            })
+    }
+
+    pub fn resolve<I: Instance>(
+        mut self,
+        registry: &SolverRegistry<I>,
+    ) -> Result<RunConfig, ConfigError> {
+        self.instance.validate()?;
+
+        let entry = registry
+            .find(&self.solver.name)
+            .ok_or_else(|| RegistryError::UnknownSolver(self.solver.name.clone()))?;
+        let arguments = entry.resolve(&self.solver_arguments())?;
+
+        let mut parameters = BTreeMap::new();
+        let mut termination = BTreeMap::new();
+        for spec in entry.parameters() {
+            if let Some(value) = arguments.get(spec.name) {
+                let value = ParameterValue::of_kind(spec.kind, value);
+                if self.termination.contains_key(spec.name) {
+                    termination.insert(spec.name.to_string(), value);
+                } else {
+                    parameters.insert(spec.name.to_string(), value);
+                }
+            }
+        }
+
+        self.solver.parameters = parameters;
+        self.termination = termination;
+        self.seed = Some(
+            self.seed
+                .unwrap_or_else(|| rand::thread_rng().gen_range(0..=MAX_SEED)),
+        );
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::matrix::Matrix;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Convergence {
+    pub branching_factor: f64,
+    pub entropy: f64,
+    pub diversity: f64,
+    pub stagnant_iterations: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RestartCriterion {
+    BranchingFactor(f64),
+    Entropy(f64),
+    Diversity(f64),
+    StagnantIterations(u32),
+    Any(Vec<RestartCriterion>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestartReason {
+    BranchingFactor,
+    Entropy,
+    Diversity,
+    Stagnation,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RestartStrategy {
+    Full,
+    Partial(f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Restart {
+    pub iteration: u32,
+    pub reason: RestartReason,
+    pub convergence: Convergence,
+}
+
+impl RestartCriterion {
+    pub fn or(self, other: RestartCriterion) -> RestartCriterion {
+        match self {
+            RestartCriterion::Any(mut criteria) => {
+                criteria.push(other);
+                RestartCriterion::Any(criteria)
+            }
+            criterion => RestartCriterion::Any(vec![criterion, other]),
+        }
+    }
+
+    pub fn check(&self, convergence: &Convergence) -> Option<RestartReason> {
+        match self {
+            RestartCriterion::BranchingFactor(min_branching_factor) => {
+                (convergence.branching_factor <= *min_branching_factor)
+                    .then_some(RestartReason::BranchingFactor)
+            }
+            RestartCriterion::Entropy(min_entropy) => {
+                (convergence.entropy <= *min_entropy).then_some(RestartReason::Entropy)
+            }
+            RestartCriterion::Diversity(min_diversity) => {
+                (convergence.diversity <= *min_diversity).then_some(RestartReason::Diversity)
+            }
+            RestartCriterion::StagnantIterations(max_stagnant_iterations) => {
+                (convergence.stagnant_iterations >= *max_stagnant_iterations)
+                    .then_some(RestartReason::Stagnation)
+            }
+            RestartCriterion::Any(criteria) => criteria
+                .iter()
+                .find_map(|criterion| criterion.check(convergence)),
+        }
+    }
+}
+
+impl RestartStrategy {
+    pub fn apply(
+        &self,
+        pheromone_matrix: &mut Matrix<f64>,
+        initial_pheromone_matrix: &Matrix<f64>,
+    ) {
+        let ratio = match self {
+            RestartStrategy::Full => 1.0,
+            RestartStrategy::Partial(ratio) => ratio.clamp(0.0, 1.0),
+        };
+
+        let pheromones = pheromone_matrix.as_mut_slice().iter_mut();
+        let initial_pheromones = initial_pheromone_matrix.as_slice().iter();
+
+        for (pheromone, initial_pheromone) in pheromones.zip(initial_pheromones) {
+            *pheromone += ratio * (initial_pheromone - *pheromone);
+        }
+    }
+}
+
+impl Display for RestartReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            RestartReason::BranchingFactor => "branching factor too low",
+            RestartReason::Entropy => "pheromone entropy too low",
+            RestartReason::Diversity => "population diversity too low",
+            RestartReason::Stagnation => "no improvement",
+        };
+
+        write!(f, "{reason}")
+    }
+}
+
+pub fn branching_factor<P: Iterator<Item = f64> + Clone>(pheromones: P, lambda: f64) -> usize {
+    let min_pheromone = pheromones.clone().fold(f64::INFINITY, f64::min);
+    let max_pheromone = pheromones.clone().fold(f64::NEG_INFINITY, f64::max);
+    let threshold = min_pheromone + lambda * (max_pheromone - min_pheromone);
+
+    pheromones
+        .filter(|pheromone| *pheromone >= threshold)
+        .count()
+}
+
+pub fn entropy<P: Iterator<Item = f64> + Clone>(pheromones: P) -> f64 {
+    let neighbors_count = pheromones.clone().count();
+    if neighbors_count < 2 {
+        return 0.0;
+    }
+
+    let total: f64 = pheromones.clone().sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+
+    let entropy = -pheromones
+        .map(|pheromone| pheromone / total)
+        .filter(|probability| *probability > 0.0)
+        .map(|probability| probability * probability.ln())
+        .sum::<f64>();
+
+    entropy / (neighbors_count as f64).ln()
+}
@@ -1,11 +1,15 @@
-use crate::models::{AdjMatrix, Solver, VisitedVecExt, Way};
+use crate::{
+    instance::Instance,
+    models::{Solver, VisitedVecExt, Way},
+    weight::Weight,
+};
 
 pub struct GreedySolver {}
 
-impl Solver for GreedySolver {
-    fn solve<'a>(&self, adj_matrix: &'a AdjMatrix<u32>) -> Way<'a> {
+impl<I: Instance> Solver<I> for GreedySolver {
+    fn solve<'a>(&self, instance: &'a I) -> Way<'a, I> {
         const START_NODE: usize = 0;
-        let nodes_count = adj_matrix.len();
+        let nodes_count = instance.nodes_count();
 
         let mut visited = vec![false; nodes_count];
         let mut way: Vec<usize> = Vec::with_capacity(nodes_count);
@@ -16,10 +20,10 @@ impl Solver for GreedySolver {
             way.push(node);
 
             node = match visited.available_neighbors().min_by(|first, second| {
-                let first = adj_matrix[node][*first];
-                let second = adj_matrix[node][*second];
+                let first = instance.distance(node, *first);
+                let second = instance.distance(node, *second);
 
-                first.cmp(&second)
+                first.total_cmp(&second)
             }) {
                 Some(next_node) => next_node,
                 None => break,
@@ -27,6 +31,6 @@ impl Solver for GreedySolver {
         }
         way.push(START_NODE);
 
-        Way::new(adj_matrix, way)
+        Way::new(instance, way)
     }
 }
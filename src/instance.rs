@@ -0,0 +1,210 @@
+use std::marker::PhantomData;
+
+use crate::{models::AdjMatrix, weight::Weight};
+
+pub type ScoreOf<I> = <<I as Instance>::Weight as Weight>::Score;
+
+pub trait Instance: Sync {
+    type Weight: Weight;
+
+    fn nodes_count(&self) -> usize;
+
+    fn distance(&self, from: usize, to: usize) -> Self::Weight;
+
+    fn node_coordinates(&self, _node: usize) -> Option<(f64, f64)> {
+        None
+    }
+
+    fn is_symmetric(&self) -> bool {
+        false
+    }
+
+    fn is_metric(&self) -> bool {
+        false
+    }
+
+    fn rounding_error(&self) -> f64 {
+        0.0
+    }
+}
+
+impl<W: Weight> Instance for AdjMatrix<W> {
+    type Weight = W;
+
+    #[inline]
+    fn nodes_count(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn distance(&self, from: usize, to: usize) -> W {
+        self[from][to]
+    }
+
+    fn is_symmetric(&self) -> bool {
+        AdjMatrix::is_symmetric(self)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    Euclidean,
+    Manhattan,
+    Chebyshev,
+    GreatCircle,
+    TsplibAtt,
+    TsplibGeo,
+}
+
+impl Metric {
+    pub fn distance(&self, from: (f64, f64), to: (f64, f64)) -> f64 {
+        let (from_x, from_y) = from;
+        let (to_x, to_y) = to;
+
+        let dx = from_x - to_x;
+        let dy = from_y - to_y;
+
+        match self {
+            Metric::Euclidean => (dx * dx + dy * dy).sqrt(),
+            Metric::Manhattan => dx.abs() + dy.abs(),
+            Metric::Chebyshev => dx.abs().max(dy.abs()),
+            Metric::GreatCircle => Metric::great_circle_distance(from, to),
+            Metric::TsplibAtt => Metric::att_distance(dx, dy),
+            Metric::TsplibGeo => Metric::geo_distance(from, to),
+        }
+    }
+
+    fn great_circle_distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
+
+        let (from_latitude, from_longitude) = (from.0.to_radians(), from.1.to_radians());
+        let (to_latitude, to_longitude) = (to.0.to_radians(), to.1.to_radians());
+
+        let latitude_delta = to_latitude - from_latitude;
+        let longitude_delta = to_longitude - from_longitude;
+
+        let haversine = (latitude_delta / 2.0).sin().powi(2)
+            + from_latitude.cos() * to_latitude.cos() * (longitude_delta / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_KM * haversine.sqrt().min(1.0).asin()
+    }
+
+    fn att_distance(dx: f64, dy: f64) -> f64 {
+        let pseudo_distance = ((dx * dx + dy * dy) / 10.0).sqrt();
+        let rounded = pseudo_distance.round();
+
+        if rounded < pseudo_distance {
+            rounded + 1.0
+        } else {
+            rounded
+        }
+    }
+
+    fn geo_distance(from: (f64, f64), to: (f64, f64)) -> f64 {
+        const EARTH_RADIUS_KM: f64 = 6378.388;
+
+        let (from_latitude, from_longitude) =
+            (Metric::geo_radians(from.0), Metric::geo_radians(from.1));
+        let (to_latitude, to_longitude) = (Metric::geo_radians(to.0), Metric::geo_radians(to.1));
+
+        let q1 = (from_longitude - to_longitude).cos();
+        let q2 = (from_latitude - to_latitude).cos();
+        let q3 = (from_latitude + to_latitude).cos();
+
+        let angle = (0.5 * ((1.0 + q1) * q2 - (1.0 - q1) * q3))
+            .clamp(-1.0, 1.0)
+            .acos();
+
+        (EARTH_RADIUS_KM * angle + 1.0).trunc()
+    }
+
+    fn geo_radians(coordinate: f64) -> f64 {
+        #[allow(clippy::approx_constant)]
+        const TSPLIB_PI: f64 = 3.141592;
+
+        let degrees = coordinate.trunc();
+        let minutes = coordinate - degrees;
+
+        TSPLIB_PI * (degrees + 5.0 * minutes / 3.0) / 180.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoordinateInstance<W: Weight> {
+    coordinates: Vec<(f64, f64)>,
+    metric: Metric,
+    weight: PhantomData<W>,
+}
+
+impl<W: Weight> CoordinateInstance<W> {
+    pub fn new(coordinates: Vec<(f64, f64)>, metric: Metric) -> Self {
+        Self {
+            coordinates,
+            metric,
+            weight: PhantomData,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn coordinates(&self) -> &Vec<(f64, f64)> {
+        &self.coordinates
+    }
+
+    #[allow(dead_code)]
+    pub fn metric(&self) -> Metric {
+        self.metric
+    }
+
+    #[allow(dead_code)]
+    pub fn to_adj_matrix(&self) -> AdjMatrix<W> {
+        let nodes_count = self.nodes_count();
+
+        AdjMatrix::from_fn(nodes_count, nodes_count, |from, to| self.distance(from, to))
+    }
+}
+
+impl<W: Weight> Instance for CoordinateInstance<W> {
+    type Weight = W;
+
+    #[inline]
+    fn nodes_count(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    #[inline]
+    fn distance(&self, from: usize, to: usize) -> W {
+        if from == to {
+            return W::default();
+        }
+
+        W::from_f64(
+            self.metric
+                .distance(self.coordinates[from], self.coordinates[to]),
+        )
+    }
+
+    #[inline]
+    fn node_coordinates(&self, node: usize) -> Option<(f64, f64)> {
+        Some(self.coordinates[node])
+    }
+
+    fn is_symmetric(&self) -> bool {
+        true
+    }
+
+    fn is_metric(&self) -> bool {
+        !W::INTEGRAL
+            && matches!(
+                self.metric,
+                Metric::Euclidean | Metric::Manhattan | Metric::Chebyshev | Metric::GreatCircle
+            )
+    }
+
+    fn rounding_error(&self) -> f64 {
+        if W::INTEGRAL || matches!(self.metric, Metric::TsplibAtt | Metric::TsplibGeo) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
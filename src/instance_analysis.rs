@@ -0,0 +1,211 @@
+use crate::{
+    instance::Instance,
+    weight::{Score, Weight},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    Symmetric,
+    Asymmetric,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metricity {
+    Metric,
+    NearMetric,
+    NonMetric,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TriangleViolation {
+    pub from: usize,
+    pub via: usize,
+    pub to: usize,
+    pub direct: f64,
+    pub detour: f64,
+}
+
+pub struct InstanceAnalysis {
+    nodes_count: usize,
+    asymmetric_pairs: usize,
+    first_asymmetric_pair: Option<(usize, usize)>,
+    triangle_checks: u64,
+    triangle_violations: u64,
+    first_triangle_violation: Option<(usize, usize, usize)>,
+    max_violation: f64,
+    max_violation_ratio: f64,
+}
+
+impl InstanceAnalysis {
+    const NEAR_METRIC_RATIO: f64 = 1.1;
+
+    pub fn new<I: Instance>(instance: &I) -> Self {
+        let nodes_count = instance.nodes_count();
+
+        let mut asymmetric_pairs = 0;
+        let mut first_asymmetric_pair = None;
+        for pair in find_asymmetric_pairs(instance) {
+            asymmetric_pairs += 1;
+            first_asymmetric_pair.get_or_insert(pair);
+        }
+
+        let triangle_checks = (nodes_count as u64)
+            * (nodes_count.saturating_sub(1) as u64)
+            * (nodes_count.saturating_sub(2) as u64);
+        let mut triangle_violations = 0;
+        let mut first_triangle_violation = None;
+        let mut max_violation: f64 = 0.0;
+        let mut max_violation_ratio: f64 = 1.0;
+
+        for violation in find_triangle_violations(instance, Symmetry::Asymmetric) {
+            let TriangleViolation {
+                from,
+                via,
+                to,
+                direct,
+                detour,
+            } = violation;
+
+            triangle_violations += 1;
+            first_triangle_violation.get_or_insert((from, via, to));
+            max_violation = max_violation.max(direct - detour);
+            max_violation_ratio = max_violation_ratio.max(if detour == 0.0 {
+                f64::INFINITY
+            } else {
+                direct / detour
+            });
+        }
+
+        Self {
+            nodes_count,
+            asymmetric_pairs,
+            first_asymmetric_pair,
+            triangle_checks,
+            triangle_violations,
+            first_triangle_violation,
+            max_violation,
+            max_violation_ratio,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn nodes_count(&self) -> usize {
+        self.nodes_count
+    }
+
+    pub fn symmetry(&self) -> Symmetry {
+        if self.asymmetric_pairs == 0 {
+            Symmetry::Symmetric
+        } else {
+            Symmetry::Asymmetric
+        }
+    }
+
+    pub fn metricity(&self) -> Metricity {
+        if self.triangle_violations == 0 {
+            Metricity::Metric
+        } else if self.max_violation_ratio <= Self::NEAR_METRIC_RATIO {
+            Metricity::NearMetric
+        } else {
+            Metricity::NonMetric
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn asymmetric_pairs(&self) -> usize {
+        self.asymmetric_pairs
+    }
+
+    pub fn first_asymmetric_pair(&self) -> Option<(usize, usize)> {
+        self.first_asymmetric_pair
+    }
+
+    #[allow(dead_code)]
+    pub fn triangle_checks(&self) -> u64 {
+        self.triangle_checks
+    }
+
+    #[allow(dead_code)]
+    pub fn triangle_violations(&self) -> u64 {
+        self.triangle_violations
+    }
+
+    pub fn first_triangle_violation(&self) -> Option<(usize, usize, usize)> {
+        self.first_triangle_violation
+    }
+
+    #[allow(dead_code)]
+    pub fn max_violation(&self) -> f64 {
+        self.max_violation
+    }
+
+    #[allow(dead_code)]
+    pub fn max_violation_ratio(&self) -> f64 {
+        self.max_violation_ratio
+    }
+
+    #[allow(dead_code)]
+    pub fn violation_rate(&self) -> f64 {
+        if self.triangle_checks == 0 {
+            0.0
+        } else {
+            self.triangle_violations as f64 / self.triangle_checks as f64
+        }
+    }
+}
+
+pub fn find_asymmetric_pairs<I: Instance>(
+    instance: &I,
+) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let nodes_count = instance.nodes_count();
+
+    (0..nodes_count)
+        .flat_map(move |row| (row + 1..nodes_count).map(move |column| (row, column)))
+        .filter(|(row, column)| {
+            instance.distance(*row, *column) != instance.distance(*column, *row)
+        })
+}
+
+pub fn find_triangle_violations<I: Instance>(
+    instance: &I,
+    symmetry: Symmetry,
+) -> impl Iterator<Item = TriangleViolation> + '_ {
+    const TRIANGLE_EDGES_COUNT: f64 = 3.0;
+
+    let nodes_count = instance.nodes_count();
+    let tolerance = TRIANGLE_EDGES_COUNT * instance.rounding_error();
+
+    (0..nodes_count).flat_map(move |from| {
+        let first_to = match symmetry {
+            Symmetry::Symmetric => from + 1,
+            Symmetry::Asymmetric => 0,
+        };
+
+        (first_to..nodes_count)
+            .filter(move |to| *to != from)
+            .flat_map(move |to| {
+                let direct = instance.distance(from, to).to_score();
+
+                (0..nodes_count)
+                    .filter(move |via| *via != from && *via != to)
+                    .filter_map(move |via| {
+                        let detour = instance.distance(from, via).to_score()
+                            + instance.distance(via, to).to_score();
+
+                        let violated = if tolerance > 0.0 {
+                            direct.to_f64() > detour.to_f64() + tolerance
+                        } else {
+                            direct.exceeds(detour)
+                        };
+
+                        violated.then(|| TriangleViolation {
+                            from,
+                            via,
+                            to,
+                            direct: direct.to_f64(),
+                            detour: detour.to_f64(),
+                        })
+                    })
+            })
+    })
+}
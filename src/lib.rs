@@ -0,0 +1,20 @@
+pub mod ant_q_solver;
+pub mod cancellation;
+pub mod candidate_lists;
+pub mod checkpoint;
+pub mod christofides_solver;
+pub mod config;
+pub mod convergence;
+pub mod greedy_solver;
+pub mod instance;
+pub mod instance_analysis;
+pub mod lower_bounds;
+pub mod matrix;
+pub mod models;
+pub mod observer;
+pub mod perfect_matching;
+pub mod portfolio_solver;
+pub mod rand_utils;
+pub mod registry;
+pub mod termination;
+pub mod weight;
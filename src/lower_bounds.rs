@@ -0,0 +1,275 @@
+use crate::{
+    instance::{Instance, ScoreOf},
+    weight::{Score, Weight},
+};
+
+pub struct LowerBounds<S: Score> {
+    assignment: S,
+    one_tree: S,
+    held_karp: S,
+}
+
+impl<S: Score> LowerBounds<S> {
+    pub fn new<I: Instance>(instance: &I, upper_bound: S, max_iterations: u32) -> Self
+    where
+        I::Weight: Weight<Score = S>,
+    {
+        Self {
+            assignment: assignment_bound(instance),
+            one_tree: one_tree_bound(instance),
+            held_karp: held_karp_bound(instance, upper_bound, max_iterations),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn assignment(&self) -> S {
+        self.assignment
+    }
+
+    #[allow(dead_code)]
+    pub fn one_tree(&self) -> S {
+        self.one_tree
+    }
+
+    #[allow(dead_code)]
+    pub fn held_karp(&self) -> S {
+        self.held_karp
+    }
+
+    pub fn best(&self) -> S {
+        [self.assignment, self.one_tree, self.held_karp]
+            .into_iter()
+            .max_by(S::total_cmp)
+            .unwrap()
+    }
+
+    pub fn gap(&self, score: S) -> Option<f64> {
+        let bound = self.best().to_f64();
+        if bound == 0.0 {
+            return None;
+        }
+
+        Some((score.to_f64() - bound) / bound * 100.0)
+    }
+}
+
+pub fn assignment_bound<I: Instance>(instance: &I) -> ScoreOf<I> {
+    let nodes_count = instance.nodes_count();
+    if nodes_count < 2 {
+        return ScoreOf::<I>::default();
+    }
+
+    let max_weight = (0..nodes_count)
+        .flat_map(|from| (0..nodes_count).map(move |to| (from, to)))
+        .map(|(from, to)| instance.distance(from, to).to_f64())
+        .fold(0.0, f64::max);
+    let forbidden = (max_weight + 1.0) * nodes_count as f64;
+
+    let cost = |row: usize, column: usize| {
+        if row == column {
+            forbidden
+        } else {
+            instance.distance(row - 1, column - 1).to_f64()
+        }
+    };
+
+    let mut row_potential = vec![0.0; nodes_count + 1];
+    let mut column_potential = vec![0.0; nodes_count + 1];
+    let mut assigned_row = vec![0usize; nodes_count + 1];
+    let mut previous_column = vec![0usize; nodes_count + 1];
+
+    for row in 1..=nodes_count {
+        assigned_row[0] = row;
+
+        let mut column = 0;
+        let mut min_slack = vec![f64::INFINITY; nodes_count + 1];
+        let mut used = vec![false; nodes_count + 1];
+
+        loop {
+            used[column] = true;
+
+            let current_row = assigned_row[column];
+            let mut delta = f64::INFINITY;
+            let mut next_column = 0;
+
+            for candidate in 1..=nodes_count {
+                if used[candidate] {
+                    continue;
+                }
+
+                let slack = cost(current_row, candidate)
+                    - row_potential[current_row]
+                    - column_potential[candidate];
+                if slack < min_slack[candidate] {
+                    min_slack[candidate] = slack;
+                    previous_column[candidate] = column;
+                }
+                if min_slack[candidate] < delta {
+                    delta = min_slack[candidate];
+                    next_column = candidate;
+                }
+            }
+
+            for candidate in 0..=nodes_count {
+                if used[candidate] {
+                    row_potential[assigned_row[candidate]] += delta;
+                    column_potential[candidate] -= delta;
+                } else {
+                    min_slack[candidate] -= delta;
+                }
+            }
+
+            column = next_column;
+            if assigned_row[column] == 0 {
+                break;
+            }
+        }
+
+        while column != 0 {
+            let previous = previous_column[column];
+            assigned_row[column] = assigned_row[previous];
+            column = previous;
+        }
+    }
+
+    let bound = (1..=nodes_count)
+        .map(|column| cost(assigned_row[column], column))
+        .sum();
+
+    ScoreOf::<I>::from_bound(bound)
+}
+
+pub fn one_tree_bound<I: Instance>(instance: &I) -> ScoreOf<I> {
+    let nodes_count = instance.nodes_count();
+    if nodes_count < 3 {
+        return ScoreOf::<I>::default();
+    }
+
+    let penalties = vec![0.0; nodes_count];
+    let (cost, _) = one_tree(instance, &penalties);
+
+    ScoreOf::<I>::from_bound(cost)
+}
+
+pub fn held_karp_bound<I: Instance>(
+    instance: &I,
+    upper_bound: ScoreOf<I>,
+    max_iterations: u32,
+) -> ScoreOf<I> {
+    const INITIAL_STEP_SCALE: f64 = 2.0;
+    const MIN_STEP_SCALE: f64 = 1e-6;
+    const STAGNATION_PERIOD: u32 = 10;
+
+    let nodes_count = instance.nodes_count();
+    if nodes_count < 3 {
+        return ScoreOf::<I>::default();
+    }
+
+    let upper_bound = upper_bound.to_f64();
+    let mut penalties = vec![0.0; nodes_count];
+    let mut best_bound = f64::MIN;
+    let mut step_scale = INITIAL_STEP_SCALE;
+    let mut iterations_without_improvement = 0;
+
+    for _ in 0..max_iterations {
+        let (cost, degrees) = one_tree(instance, &penalties);
+        let bound = cost - 2.0 * penalties.iter().sum::<f64>();
+
+        if bound > best_bound {
+            best_bound = bound;
+            iterations_without_improvement = 0;
+        } else {
+            iterations_without_improvement += 1;
+            if iterations_without_improvement >= STAGNATION_PERIOD {
+                step_scale /= 2.0;
+                iterations_without_improvement = 0;
+            }
+        }
+
+        let subgradient_norm: i64 = degrees
+            .iter()
+            .map(|degree| (*degree as i64 - 2).pow(2))
+            .sum();
+        if subgradient_norm == 0 || step_scale < MIN_STEP_SCALE {
+            break;
+        }
+
+        let step = step_scale * (upper_bound - bound).max(0.0) / subgradient_norm as f64;
+        if step == 0.0 {
+            break;
+        }
+
+        for (penalty, degree) in penalties.iter_mut().zip(&degrees) {
+            *penalty += step * (*degree as f64 - 2.0);
+        }
+    }
+
+    ScoreOf::<I>::from_bound(best_bound)
+}
+
+fn one_tree<I: Instance>(instance: &I, penalties: &[f64]) -> (f64, Vec<usize>) {
+    const SPECIAL_NODE: usize = 0;
+    const ROOT_NODE: usize = 1;
+
+    let nodes_count = instance.nodes_count();
+    let weight = |from: usize, to: usize| {
+        instance
+            .distance(from, to)
+            .to_f64()
+            .min(instance.distance(to, from).to_f64())
+            + penalties[from]
+            + penalties[to]
+    };
+
+    let mut degrees = vec![0usize; nodes_count];
+    let mut cost = 0.0;
+
+    let mut in_tree = vec![false; nodes_count];
+    let mut distance = vec![f64::INFINITY; nodes_count];
+    let mut parent = vec![ROOT_NODE; nodes_count];
+
+    in_tree[SPECIAL_NODE] = true;
+    distance[ROOT_NODE] = 0.0;
+
+    for _ in 1..nodes_count {
+        let mut node = ROOT_NODE;
+        let mut node_distance = f64::INFINITY;
+        for candidate in 0..nodes_count {
+            if !in_tree[candidate] && distance[candidate] < node_distance {
+                node = candidate;
+                node_distance = distance[candidate];
+            }
+        }
+
+        in_tree[node] = true;
+        if node != ROOT_NODE {
+            cost += node_distance;
+            degrees[node] += 1;
+            degrees[parent[node]] += 1;
+        }
+
+        for neighbor in 0..nodes_count {
+            if !in_tree[neighbor] {
+                let neighbor_distance = weight(node, neighbor);
+                if neighbor_distance < distance[neighbor] {
+                    distance[neighbor] = neighbor_distance;
+                    parent[neighbor] = node;
+                }
+            }
+        }
+    }
+
+    let mut special_edges: Vec<(f64, usize)> = (0..nodes_count)
+        .filter(|node| *node != SPECIAL_NODE)
+        .map(|node| (weight(SPECIAL_NODE, node), node))
+        .collect();
+    special_edges.sort_by(|first, second| first.0.total_cmp(&second.0));
+
+    for (edge_weight, node) in special_edges.into_iter().take(2) {
+        cost += edge_weight;
+        degrees[node] += 1;
+        degrees[SPECIAL_NODE] += 1;
+    }
+
+    (cost, degrees)
+}
@@ -1,20 +1,182 @@
-use cli_utils::{adj_matrix, solver};
+use std::{env, process};
+
+use cea::{
+    cancellation::CancellationToken,
+    config::{OutputFormat, RunConfig},
+    instance::{Instance, ScoreOf},
+    lower_bounds::LowerBounds,
+    models::AdjMatrix,
+    observer::{ConsoleObserver, CsvObserver, Observer},
+    registry::{BuildContext, SolverRegistry},
+    termination::StopReason,
+    weight::Score,
+};
+use cli_utils::{command, instance, solver, usage, AnyInstance, Command};
 
 mod cli_utils;
-mod ant_q_solver;
-mod greedy_solver;
-mod models;
-mod rand_utils;
+
+const LOWER_BOUND_ITERATIONS: u32 = 1000;
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+const SOLVE_ERROR_EXIT_CODE: i32 = 1;
+const CONFIG_ERROR_EXIT_CODE: i32 = 2;
 
 fn main() {
-    const RANDOM_SEED: Option<u64> = None;
+    let registry = SolverRegistry::<AdjMatrix<u32>>::default();
+
+    let config = match command(&registry, env::args().skip(1)).unwrap() {
+        Command::Help => {
+            print!("{}", usage(&registry));
+            return;
+        }
+        Command::Run(config) => match config.resolve(&registry) {
+            Ok(config) => config,
+            Err(error) => {
+                eprintln!("Invalid configuration: {error:?}");
+                process::exit(CONFIG_ERROR_EXIT_CODE);
+            }
+        },
+    };
+
+    match instance(&config.instance, config.rng_algorithm, config.seed).unwrap() {
+        AnyInstance::IntegerMatrix(instance) => run(&instance, &config),
+        AnyInstance::RealMatrix(instance) => run(&instance, &config),
+        AnyInstance::IntegerCoordinates(instance) => run(&instance, &config),
+        AnyInstance::RealCoordinates(instance) => run(&instance, &config),
+    }
+}
+
+fn run<I: Instance + 'static>(instance: &I, config: &RunConfig) {
+    let cancellation_token = CancellationToken::new();
+    let context = BuildContext {
+        rng_algorithm: config.rng_algorithm,
+        random_seed: config.seed,
+        cancellation_token: cancellation_token.clone(),
+    };
+    let solver = solver(&SolverRegistry::default(), config, &context).unwrap();
+
+    let handler_token = cancellation_token.clone();
+    ctrlc::set_handler(move || {
+        if handler_token.is_cancelled() {
+            process::exit(INTERRUPTED_EXIT_CODE);
+        }
+        handler_token.cancel();
+    })
+    .unwrap();
+
+    if config.output.format == OutputFormat::Text {
+        match config.to_toml() {
+            Ok(configuration) => {
+                println!("Configuration:");
+                println!("{configuration}");
+            }
+            Err(error) => {
+                eprintln!("Unable to serialize configuration: {error:?}");
+                process::exit(CONFIG_ERROR_EXIT_CODE);
+            }
+        }
+    }
+
+    let mut csv_observer = config
+        .output
+        .progress_csv
+        .as_ref()
+        .map(|path| CsvObserver::create(path).unwrap());
+
+    let mut observer: Vec<Box<dyn Observer<ScoreOf<I>> + '_>> = Vec::new();
+    if config.output.progress_interval > 0 {
+        observer.push(Box::new(ConsoleObserver::new(
+            config.output.progress_interval,
+        )));
+    }
+    if let Some(csv_observer) = &mut csv_observer {
+        observer.push(Box::new(csv_observer));
+    }
+
+    let solution = match solver.try_solve_with_observer(instance, &mut observer) {
+        Ok(solution) => solution,
+        Err(error) => {
+            eprintln!("Unable to solve instance: {error:?}");
+            process::exit(SOLVE_ERROR_EXIT_CODE);
+        }
+    };
+    drop(observer);
+
+    if let Some(Err(error)) = csv_observer.map(CsvObserver::finish) {
+        eprintln!("Unable to write progress CSV: {error}");
+    }
+
+    let stop_reason = solution.stop_reason();
+    let portfolio_entries = solution.portfolio_entries();
+    let way = solution.way();
+    if let Err(error) = way.validate() {
+        eprintln!("Solver returned an invalid tour: {error:?}");
+        process::exit(SOLVE_ERROR_EXIT_CODE);
+    }
+    let score = way.score();
+
+    let computes_lower_bounds =
+        config.output.lower_bounds && stop_reason != StopReason::Interrupted;
+
+    match config.output.format {
+        OutputFormat::Text => {
+            println!("Way: {way}");
+            println!("Score: {score}");
+            println!("Stop reason: {stop_reason}");
 
-    let solver = solver(RANDOM_SEED).unwrap();
-    let adj_matrix = adj_matrix(RANDOM_SEED).unwrap();
+            if !portfolio_entries.is_empty() {
+                println!("Portfolio:");
+                for entry in portfolio_entries {
+                    match (&entry.score, &entry.stop_reason, &entry.error) {
+                        (Some(score), Some(stop_reason), _) => println!(
+                            "  {}: score {score}, {stop_reason}, {:.3}s",
+                            entry.name,
+                            entry.elapsed.as_secs_f64()
+                        ),
+                        (_, _, error) => println!(
+                            "  {}: failed after {:.3}s: {error:?}",
+                            entry.name,
+                            entry.elapsed.as_secs_f64()
+                        ),
+                    }
+                }
+            }
 
-    let solution = solver.solve(&adj_matrix);
-    let score = solution.score();
+            if computes_lower_bounds {
+                let lower_bounds = LowerBounds::new(instance, score, LOWER_BOUND_ITERATIONS);
+                println!("Lower bound: {}", lower_bounds.best());
+                match lower_bounds.gap(score) {
+                    Some(gap) => println!("Gap: {gap:.2}%"),
+                    None => println!("Gap: unknown"),
+                }
+            }
+        }
+        OutputFormat::Json => {
+            let lower_bounds = computes_lower_bounds
+                .then(|| LowerBounds::new(instance, score, LOWER_BOUND_ITERATIONS));
 
-    println!("Way: {solution}");
-    println!("Score: {score}");
+            let mut output = serde_json::json!({
+                "configuration": config,
+                "way": way.way(),
+                "score": score.to_f64(),
+                "stop_reason": stop_reason.to_string(),
+                "lower_bound": lower_bounds.as_ref().map(|lower_bounds| lower_bounds.best().to_f64()),
+                "gap": lower_bounds.as_ref().and_then(|lower_bounds| lower_bounds.gap(score)),
+            });
+            if !portfolio_entries.is_empty() {
+                output["portfolio"] = portfolio_entries
+                    .iter()
+                    .map(|entry| {
+                        serde_json::json!({
+                            "name": entry.name,
+                            "score": entry.score.map(|score| score.to_f64()),
+                            "stop_reason": entry.stop_reason.map(|reason| reason.to_string()),
+                            "elapsed": entry.elapsed.as_secs_f64(),
+                            "error": entry.error.as_ref().map(|error| format!("{error:?}")),
+                        })
+                    })
+                    .collect();
+            }
+            println!("{}", serde_json::to_string_pretty(&output).unwrap());
+        }
+    }
 }
@@ -0,0 +1,287 @@
+use std::{
+    ops::{Index, IndexMut},
+    sync::OnceLock,
+};
+
+use serde::{Deserialize, Serialize};
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum MatrixError {
+    RowLengthMismatch {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    AllocationFailed {
+        rows_count: usize,
+        columns_count: usize,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Matrix<T> {
+    rows_count: usize,
+    columns_count: usize,
+    data: Vec<T>,
+    #[serde(skip)]
+    symmetric: OnceLock<bool>,
+}
+
+impl<T: Clone> Matrix<T> {
+    pub fn new(rows_count: usize, columns_count: usize, value: T) -> Self {
+        Self {
+            rows_count,
+            columns_count,
+            data: vec![value; rows_count * columns_count],
+            symmetric: OnceLock::new(),
+        }
+    }
+
+    pub fn square(size: usize, value: T) -> Self {
+        Self::new(size, size, value)
+    }
+
+    #[allow(dead_code)]
+    pub fn fill(&mut self, value: T) {
+        self.invalidate_symmetry();
+        self.data.fill(value);
+    }
+
+    #[allow(dead_code)]
+    pub fn to_nested(&self) -> Vec<Vec<T>> {
+        self.rows().map(<[T]>::to_vec).collect()
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(
+        rows_count: usize,
+        columns_count: usize,
+        mut element: F,
+    ) -> Self {
+        let mut data = Vec::with_capacity(rows_count * columns_count);
+        for row in 0..rows_count {
+            for column in 0..columns_count {
+                data.push(element(row, column));
+            }
+        }
+
+        Self {
+            rows_count,
+            columns_count,
+            data,
+            symmetric: OnceLock::new(),
+        }
+    }
+
+    pub fn try_from_fn<F: FnMut(usize, usize) -> T>(
+        rows_count: usize,
+        columns_count: usize,
+        mut element: F,
+    ) -> Result<Self, MatrixError> {
+        let allocation_failed = || MatrixError::AllocationFailed {
+            rows_count,
+            columns_count,
+        };
+
+        let elements_count = rows_count
+            .checked_mul(columns_count)
+            .ok_or_else(allocation_failed)?;
+        let mut data = Vec::new();
+        data.try_reserve_exact(elements_count)
+            .map_err(|_| allocation_failed())?;
+        for row in 0..rows_count {
+            for column in 0..columns_count {
+                data.push(element(row, column));
+            }
+        }
+
+        Ok(Self {
+            rows_count,
+            columns_count,
+            data,
+            symmetric: OnceLock::new(),
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.rows_count
+    }
+
+    #[allow(dead_code)]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.rows_count == 0
+    }
+
+    #[inline]
+    pub fn rows_count(&self) -> usize {
+        self.rows_count
+    }
+
+    #[inline]
+    pub fn columns_count(&self) -> usize {
+        self.columns_count
+    }
+
+    #[inline]
+    pub fn row(&self, row: usize) -> &[T] {
+        let start = row * self.columns_count;
+        &self.data[start..start + self.columns_count]
+    }
+
+    #[inline]
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        self.invalidate_symmetry();
+        let start = row * self.columns_count;
+        &mut self.data[start..start + self.columns_count]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows_count).map(|row| self.row(row))
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.invalidate_symmetry();
+        self.data.chunks_mut(self.columns_count.max(1))
+    }
+
+    #[allow(dead_code)]
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row < self.rows_count && column < self.columns_count {
+            self.data.get(row * self.columns_count + column)
+        } else {
+            None
+        }
+    }
+
+    #[allow(dead_code)]
+    #[inline]
+    pub fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        self.invalidate_symmetry();
+        if row < self.rows_count && column < self.columns_count {
+            self.data.get_mut(row * self.columns_count + column)
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `row` must be less than the rows count and `column` less than the columns count.
+    #[allow(dead_code)]
+    #[inline]
+    pub unsafe fn get_unchecked(&self, row: usize, column: usize) -> &T {
+        self.data.get_unchecked(row * self.columns_count + column)
+    }
+
+    /// # Safety
+    ///
+    /// `row` must be less than the rows count and `column` less than the columns count.
+    #[allow(dead_code)]
+    #[inline]
+    pub unsafe fn get_unchecked_mut(&mut self, row: usize, column: usize) -> &mut T {
+        self.invalidate_symmetry();
+        self.data
+            .get_unchecked_mut(row * self.columns_count + column)
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.invalidate_symmetry();
+        &mut self.data
+    }
+
+    #[inline]
+    fn invalidate_symmetry(&mut self) {
+        self.symmetric.take();
+    }
+
+    #[allow(dead_code)]
+    pub fn into_nested(self) -> Vec<Vec<T>> {
+        let columns_count = self.columns_count;
+        let mut data = self.data.into_iter();
+
+        (0..self.rows_count)
+            .map(|_| data.by_ref().take(columns_count).collect())
+            .collect()
+    }
+}
+
+impl<T: PartialEq> Matrix<T> {
+    pub fn is_symmetric(&self) -> bool {
+        *self.symmetric.get_or_init(|| {
+            self.rows_count == self.columns_count
+                && (0..self.rows_count).all(|row| {
+                    (row + 1..self.columns_count).all(|column| {
+                        self.data[row * self.columns_count + column]
+                            == self.data[column * self.columns_count + row]
+                    })
+                })
+        })
+    }
+}
+
+impl<T: PartialEq> PartialEq for Matrix<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows_count == other.rows_count
+            && self.columns_count == other.columns_count
+            && self.data == other.data
+    }
+}
+
+impl<T> Index<usize> for Matrix<T> {
+    type Output = [T];
+
+    #[inline]
+    fn index(&self, row: usize) -> &[T] {
+        self.row(row)
+    }
+}
+
+impl<T> IndexMut<usize> for Matrix<T> {
+    #[inline]
+    fn index_mut(&mut self, row: usize) -> &mut [T] {
+        self.row_mut(row)
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
+    type Error = MatrixError;
+
+    fn try_from(nested: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let rows_count = nested.len();
+        let columns_count = nested.first().map_or(0, Vec::len);
+
+        if let Some((row, elements)) = nested
+            .iter()
+            .enumerate()
+            .find(|(_, elements)| elements.len() != columns_count)
+        {
+            return Err(MatrixError::RowLengthMismatch {
+                row,
+                expected: columns_count,
+                actual: elements.len(),
+            });
+        }
+
+        Ok(Self {
+            rows_count,
+            columns_count,
+            data: nested.into_iter().flatten().collect(),
+            symmetric: OnceLock::new(),
+        })
+    }
+}
+
+impl<T> From<Matrix<T>> for Vec<Vec<T>> {
+    fn from(matrix: Matrix<T>) -> Self {
+        matrix.into_nested()
+    }
+}
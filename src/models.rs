@@ -1,6 +1,26 @@
-use std::{cmp::Ordering, fmt::Display};
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    ptr,
+    time::Instant,
+};
 
-pub type AdjMatrix<T> = Vec<Vec<T>>;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    checkpoint::CheckpointError,
+    instance::{Instance, ScoreOf},
+    instance_analysis::Symmetry,
+    matrix::Matrix,
+    observer::{Observer, Progress},
+    portfolio_solver::PortfolioEntry,
+    termination::StopReason,
+    weight::{Score, Weight},
+};
+
+pub type AdjMatrix<T> = Matrix<T>;
 
 pub trait VisitedVecExt {
     #[allow(dead_code)]
@@ -10,7 +30,7 @@ pub trait VisitedVecExt {
 impl VisitedVecExt for Vec<bool> {
     fn available_neighbors(&self) -> impl Iterator<Item = usize> {
         let nodes_count = self.len();
-        (0..nodes_count).into_iter().filter(|index| !self[*index])
+        (0..nodes_count).filter(|index| !self[*index])
     }
 }
 
@@ -37,40 +57,153 @@ impl<'a> Iterator for PairIter<'a> {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WayError {
+    Empty,
+    IndexOutOfRange { position: usize, node: usize },
+    NotClosed { first: usize, last: usize },
+    NotPermutation { node: usize, visits: usize },
+}
+
 pub trait WayVecExt {
     #[allow(dead_code)]
     fn iter_edges(&self) -> impl Iterator<Item = (usize, usize)>;
+
+    fn canonical(&self, symmetry: Symmetry) -> Vec<usize>;
+
+    fn validate(&self, nodes_count: usize) -> Result<(), WayError>;
 }
 
 impl WayVecExt for Vec<usize> {
     fn iter_edges(&self) -> impl Iterator<Item = (usize, usize)> {
         PairIter {
             index: 0,
-            way: &self,
+            way: self,
+        }
+    }
+
+    fn canonical(&self, symmetry: Symmetry) -> Vec<usize> {
+        let mut canonical: Vec<usize> = canonical_nodes(self, symmetry).collect();
+        if let Some(start_node) = canonical.first() {
+            canonical.push(*start_node);
+        }
+
+        canonical
+    }
+
+    fn validate(&self, nodes_count: usize) -> Result<(), WayError> {
+        if self.is_empty() {
+            return if nodes_count == 0 {
+                Ok(())
+            } else {
+                Err(WayError::Empty)
+            };
+        }
+
+        if let Some((position, node)) = self
+            .iter()
+            .enumerate()
+            .find(|(_, node)| **node >= nodes_count)
+        {
+            return Err(WayError::IndexOutOfRange {
+                position,
+                node: *node,
+            });
+        }
+
+        let (first, last) = (self[0], self[self.len() - 1]);
+        if self.len() < 2 || first != last {
+            return Err(WayError::NotClosed { first, last });
         }
+
+        let mut visits = vec![0; nodes_count];
+        for node in cycle(self) {
+            visits[*node] += 1;
+        }
+
+        match visits
+            .into_iter()
+            .enumerate()
+            .find(|(_, visits)| *visits != 1)
+        {
+            Some((node, visits)) => Err(WayError::NotPermutation { node, visits }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[inline]
+fn cycle(way: &[usize]) -> &[usize] {
+    match way {
+        [first, .., last] if first == last => &way[..way.len() - 1],
+        _ => way,
     }
 }
 
-pub struct Way<'a> {
-    adj_matrix: &'a AdjMatrix<u32>,
+fn canonical_nodes(way: &[usize], symmetry: Symmetry) -> impl Iterator<Item = usize> + '_ {
+    let cycle = cycle(way);
+    let len = cycle.len();
+
+    let start = (0..len).min_by_key(|index| cycle[*index]).unwrap_or(0);
+    let backward = symmetry == Symmetry::Symmetric
+        && len > 2
+        && cycle[(start + len - 1) % len] < cycle[(start + 1) % len];
+
+    (0..len).map(move |offset| {
+        if backward {
+            cycle[(start + len - offset) % len]
+        } else {
+            cycle[(start + offset) % len]
+        }
+    })
+}
+
+fn edge_set(way: &Vec<usize>, symmetry: Symmetry) -> HashSet<(usize, usize)> {
+    way.iter_edges()
+        .map(|(from, to)| {
+            if symmetry == Symmetry::Symmetric && to < from {
+                (to, from)
+            } else {
+                (from, to)
+            }
+        })
+        .collect()
+}
+
+pub struct Way<'a, I: Instance = AdjMatrix<u32>> {
+    instance: &'a I,
     way: Vec<usize>,
-    score: u64,
+    score: ScoreOf<I>,
+    symmetry: Symmetry,
 }
 
-impl<'a> Way<'a> {
+impl<'a, I: Instance> Way<'a, I> {
     #[allow(dead_code)]
-    pub fn new(adj_matrix: &'a AdjMatrix<u32>, way: Vec<usize>) -> Self {
-        let score = Self::calculate_score(adj_matrix, &way);
+    pub fn new(instance: &'a I, way: Vec<usize>) -> Self {
+        let score = Self::calculate_score(instance, &way);
+        let symmetry = if instance.is_symmetric() {
+            Symmetry::Symmetric
+        } else {
+            Symmetry::Asymmetric
+        };
+
         Self {
-            adj_matrix,
+            instance,
             way,
             score,
+            symmetry,
         }
     }
 
+    pub fn try_new(instance: &'a I, way: Vec<usize>) -> Result<Self, WayError> {
+        way.validate(instance.nodes_count())?;
+
+        Ok(Self::new(instance, way))
+    }
+
     #[allow(dead_code)]
-    pub fn adj_matrix(&self) -> &AdjMatrix<u32> {
-        &self.adj_matrix
+    pub fn instance(&self) -> &'a I {
+        self.instance
     }
 
     #[allow(dead_code)]
@@ -79,30 +212,98 @@ impl<'a> Way<'a> {
     }
 
     #[allow(dead_code)]
-    pub fn score(&self) -> u64 {
+    pub fn score(&self) -> ScoreOf<I> {
         self.score
     }
 
-    fn calculate_score(adj_matrix: &AdjMatrix<u32>, way: &Vec<usize>) -> u64 {
+    pub fn symmetry(&self) -> Symmetry {
+        self.symmetry
+    }
+
+    pub fn canonical(&self) -> Vec<usize> {
+        self.way.canonical(self.symmetry)
+    }
+
+    pub fn validate(&self) -> Result<(), WayError> {
+        self.way.validate(self.instance.nodes_count())
+    }
+
+    pub fn edges_count(&self) -> usize {
+        self.way.len().saturating_sub(1)
+    }
+
+    pub fn shared_edges_count(&self, other: &Way<'_, I>) -> usize {
+        let symmetry = self.common_symmetry(other);
+        edge_set(&self.way, symmetry)
+            .intersection(&edge_set(&other.way, symmetry))
+            .count()
+    }
+
+    pub fn bond_distance(&self, other: &Way<'_, I>) -> usize {
+        self.edges_count()
+            .max(other.edges_count())
+            .saturating_sub(self.shared_edges_count(other))
+    }
+
+    pub fn edge_overlap(&self, other: &Way<'_, I>) -> f64 {
+        let edges_count = self.edges_count().max(other.edges_count());
+        if edges_count == 0 {
+            return 1.0;
+        }
+
+        self.shared_edges_count(other) as f64 / edges_count as f64
+    }
+
+    pub fn positional_distance(&self, other: &Way<'_, I>) -> usize {
+        let symmetry = self.common_symmetry(other);
+        let first: Vec<usize> = canonical_nodes(&self.way, symmetry).collect();
+        let second: Vec<usize> = canonical_nodes(&other.way, symmetry).collect();
+
+        let mismatches = first
+            .iter()
+            .zip(&second)
+            .filter(|(first, second)| first != second)
+            .count();
+
+        mismatches + first.len().abs_diff(second.len())
+    }
+
+    #[inline]
+    fn common_symmetry(&self, other: &Way<'_, I>) -> Symmetry {
+        if self.symmetry == other.symmetry {
+            self.symmetry
+        } else {
+            Symmetry::Asymmetric
+        }
+    }
+
+    pub fn to_owned_way(&self) -> OwnedWay<ScoreOf<I>> {
+        OwnedWay::new(self.way.clone(), self.score)
+    }
+
+    fn calculate_score(instance: &I, way: &Vec<usize>) -> ScoreOf<I> {
         let sum = way
             .iter_edges()
-            .map(|(from, to)| adj_matrix[from][to] as u64)
+            .map(|(from, to)| instance.distance(from, to).to_score())
             .sum();
 
         sum
     }
 }
 
-impl<'a> Display for Way<'a> {
+impl<'a, I: Instance> Display for Way<'a, I> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let way = &self.way;
 
         let way_size = way.len();
+        if way_size == 0 {
+            return Ok(());
+        }
         let right_arrows_count = way_size - 1;
 
         let mut parts: Vec<String> = Vec::with_capacity(way_size + right_arrows_count);
         for (from, to) in way.iter_edges() {
-            let weight = self.adj_matrix[from][to];
+            let weight = self.instance.distance(from, to);
 
             parts.push((from + 1).to_string());
             parts.push(format!(" -({weight})-> "));
@@ -114,36 +315,304 @@ impl<'a> Display for Way<'a> {
     }
 }
 
-impl<'a> Clone for Way<'a> {
+impl<'a, I: Instance> Clone for Way<'a, I> {
     fn clone(&self) -> Self {
         Self {
-            adj_matrix: self.adj_matrix,
+            instance: self.instance,
             way: self.way.clone(),
             score: self.score,
+            symmetry: self.symmetry,
         }
     }
 }
 
-impl<'a> Ord for Way<'a> {
+impl<'a, I: Instance> Ord for Way<'a, I> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.score.cmp(&other.score)
+        let symmetry = self.common_symmetry(other);
+
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| {
+                canonical_nodes(&self.way, symmetry).cmp(canonical_nodes(&other.way, symmetry))
+            })
+            .then_with(|| ptr::from_ref(self.instance).cmp(&ptr::from_ref(other.instance)))
     }
 }
 
-impl<'a> PartialOrd for Way<'a> {
+impl<'a, I: Instance> PartialOrd for Way<'a, I> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.score.partial_cmp(&other.score)
+        Some(self.cmp(other))
     }
 }
 
-impl<'a> PartialEq for Way<'a> {
+impl<'a, I: Instance> PartialEq for Way<'a, I> {
     fn eq(&self, other: &Self) -> bool {
-        self.adj_matrix == other.adj_matrix && self.way == other.way && self.score == other.score
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a, I: Instance> Eq for Way<'a, I> {}
+
+impl<'a, I: Instance> Hash for Way<'a, I> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for node in canonical_nodes(&self.way, self.symmetry) {
+            node.hash(state);
+        }
+    }
+}
+
+pub fn population_diversity<I: Instance>(population: &[Way<'_, I>]) -> f64 {
+    let symmetry = if population
+        .iter()
+        .all(|way| way.symmetry == Symmetry::Symmetric)
+    {
+        Symmetry::Symmetric
+    } else {
+        Symmetry::Asymmetric
+    };
+    let edge_sets: Vec<HashSet<(usize, usize)>> = population
+        .iter()
+        .map(|way| edge_set(&way.way, symmetry))
+        .collect();
+
+    let mut distance_sum = 0.0;
+    let mut pairs_count = 0;
+
+    for (index, first) in edge_sets.iter().enumerate() {
+        for second in &edge_sets[index + 1..] {
+            let edges_count = first.len().max(second.len());
+            if edges_count > 0 {
+                let shared_edges_count = first.intersection(second).count();
+                distance_sum += 1.0 - shared_edges_count as f64 / edges_count as f64;
+            }
+            pairs_count += 1;
+        }
+    }
+
+    if pairs_count == 0 {
+        return 0.0;
+    }
+
+    distance_sum / pairs_count as f64
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OwnedWay<S: Score> {
+    way: Vec<usize>,
+    score: S,
+    instance_id: Option<String>,
+}
+
+impl<S: Score> OwnedWay<S> {
+    pub fn new(way: Vec<usize>, score: S) -> Self {
+        Self {
+            way,
+            score,
+            instance_id: None,
+        }
+    }
+
+    pub fn with_instance_id(mut self, instance_id: impl Into<String>) -> Self {
+        self.instance_id = Some(instance_id.into());
+        self
+    }
+
+    pub fn way(&self) -> &Vec<usize> {
+        &self.way
+    }
+
+    pub fn score(&self) -> S {
+        self.score
+    }
+
+    pub fn instance_id(&self) -> Option<&str> {
+        self.instance_id.as_deref()
+    }
+
+    pub fn to_way<'a, I: Instance>(&self, instance: &'a I) -> Result<Way<'a, I>, SolveError>
+    where
+        I::Weight: Weight<Score = S>,
+    {
+        Way::try_new(instance, self.way.clone()).map_err(SolveError::InvalidWay)
+    }
+
+    pub fn into_way<I: Instance>(self, instance: &I) -> Result<Way<'_, I>, SolveError>
+    where
+        I::Weight: Weight<Score = S>,
+    {
+        Way::try_new(instance, self.way).map_err(SolveError::InvalidWay)
+    }
+}
+
+impl<'a, I: Instance> From<Way<'a, I>> for OwnedWay<ScoreOf<I>> {
+    fn from(way: Way<'a, I>) -> Self {
+        OwnedWay::new(way.way, way.score)
+    }
+}
+
+impl<S: Score> Display for OwnedWay<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let serialized = self
+            .way
+            .iter()
+            .map(|node| (node + 1).to_string())
+            .collect::<Vec<String>>()
+            .join(" -> ");
+
+        write!(f, "{serialized}")
+    }
+}
+
+impl<S: Score> Ord for OwnedWay<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| self.way.cmp(&other.way))
+            .then_with(|| self.instance_id.cmp(&other.instance_id))
+    }
+}
+
+impl<S: Score> PartialOrd for OwnedWay<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Score> PartialEq for OwnedWay<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S: Score> Eq for OwnedWay<S> {}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum SolveError {
+    MatrixNotSymmetric { row: usize, column: usize },
+    TriangleInequalityViolated { from: usize, via: usize, to: usize },
+    Checkpoint(CheckpointError),
+    InvalidInitialWay(WayError),
+    InvalidWay(WayError),
+    PheromoneMatrixSizeMismatch { expected: usize, actual: usize },
+    InstanceTooLarge { nodes_count: usize },
+    NoSolution,
+}
+
+pub struct Solution<'a, I: Instance = AdjMatrix<u32>> {
+    way: Way<'a, I>,
+    stop_reason: StopReason,
+    portfolio_entries: Vec<PortfolioEntry<ScoreOf<I>>>,
+}
+
+impl<'a, I: Instance> Solution<'a, I> {
+    pub fn new(way: Way<'a, I>, stop_reason: StopReason) -> Self {
+        Self {
+            way,
+            stop_reason,
+            portfolio_entries: Vec::new(),
+        }
+    }
+
+    pub fn with_portfolio_entries(mut self, entries: Vec<PortfolioEntry<ScoreOf<I>>>) -> Self {
+        self.portfolio_entries = entries;
+        self
+    }
+
+    pub fn way(&self) -> &Way<'a, I> {
+        &self.way
+    }
+
+    pub fn stop_reason(&self) -> StopReason {
+        self.stop_reason
+    }
+
+    pub fn portfolio_entries(&self) -> &Vec<PortfolioEntry<ScoreOf<I>>> {
+        &self.portfolio_entries
+    }
+
+    pub fn into_way(self) -> Way<'a, I> {
+        self.way
+    }
+}
+
+pub struct Snapshot<'a, I: Instance = AdjMatrix<u32>> {
+    iteration: u32,
+    best_way: Way<'a, I>,
+    iteration_best_way: Way<'a, I>,
+}
+
+impl<'a, I: Instance> Snapshot<'a, I> {
+    pub fn new(iteration: u32, best_way: Way<'a, I>, iteration_best_way: Way<'a, I>) -> Self {
+        Self {
+            iteration,
+            best_way,
+            iteration_best_way,
+        }
+    }
+
+    pub fn iteration(&self) -> u32 {
+        self.iteration
+    }
+
+    pub fn best_way(&self) -> &Way<'a, I> {
+        &self.best_way
+    }
+
+    pub fn iteration_best_way(&self) -> &Way<'a, I> {
+        &self.iteration_best_way
+    }
+
+    pub fn into_best_way(self) -> Way<'a, I> {
+        self.best_way
+    }
+}
+
+pub trait Solver<I: Instance = AdjMatrix<u32>> {
+    fn solve<'a>(&self, instance: &'a I) -> Way<'a, I>;
+
+    fn try_solve<'a>(&self, instance: &'a I) -> Result<Way<'a, I>, SolveError> {
+        Ok(self.solve(instance))
+    }
+
+    fn try_solve_with_observer<'a>(
+        &self,
+        instance: &'a I,
+        observer: &mut dyn Observer<ScoreOf<I>>,
+    ) -> Result<Solution<'a, I>, SolveError> {
+        let start = Instant::now();
+        let way = self.try_solve(instance)?;
+
+        observer.on_iteration(&Progress {
+            iteration: 1,
+            iteration_best_score: way.score(),
+            best_score: way.score(),
+            elapsed: start.elapsed(),
+            diversity: None,
+            restarts: 0,
+        });
+
+        Ok(Solution::new(way, StopReason::Completed))
+    }
+
+    fn try_solve_from<'a>(
+        &self,
+        instance: &'a I,
+        initial_way: Way<'a, I>,
+    ) -> Result<Way<'a, I>, SolveError> {
+        initial_way
+            .validate()
+            .map_err(SolveError::InvalidInitialWay)?;
+        let way = self.try_solve(instance)?;
+
+        Ok(way.min(initial_way))
     }
 }
 
-impl<'a> Eq for Way<'a> {}
+pub trait IterativeSolver<I: Instance = AdjMatrix<u32>>: Solver<I> {
+    type Iterations<'a>: Iterator<Item = Snapshot<'a, I>>
+    where
+        I: 'a;
 
-pub trait Solver {
-    fn solve<'a>(&self, adj_matrix: &'a AdjMatrix<u32>) -> Way<'a>;
+    fn iterations<'a>(&self, instance: &'a I) -> Result<Self::Iterations<'a>, SolveError>;
 }
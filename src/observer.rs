@@ -0,0 +1,314 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Stderr, Write},
+    path::Path,
+    time::Duration,
+};
+
+use crate::{
+    checkpoint::CheckpointError, convergence::Restart, termination::StopReason, weight::Score,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Progress<S: Score> {
+    pub iteration: u32,
+    pub iteration_best_score: S,
+    pub best_score: S,
+    pub elapsed: Duration,
+    pub diversity: Option<f64>,
+    pub restarts: u32,
+}
+
+pub trait Observer<S: Score> {
+    fn on_iteration(&mut self, progress: &Progress<S>);
+
+    fn on_restart(&mut self, _restart: &Restart) {}
+
+    fn on_checkpoint_error(&mut self, _iteration: u32, _error: &CheckpointError) {}
+
+    fn tracks_diversity(&self) -> bool {
+        false
+    }
+
+    fn requested_stop(&self) -> Option<StopReason> {
+        None
+    }
+}
+
+impl<S: Score> Observer<S> for () {
+    fn on_iteration(&mut self, _progress: &Progress<S>) {}
+}
+
+impl<S: Score, O: Observer<S> + ?Sized> Observer<S> for &mut O {
+    fn on_iteration(&mut self, progress: &Progress<S>) {
+        (**self).on_iteration(progress);
+    }
+
+    fn on_restart(&mut self, restart: &Restart) {
+        (**self).on_restart(restart);
+    }
+
+    fn on_checkpoint_error(&mut self, iteration: u32, error: &CheckpointError) {
+        (**self).on_checkpoint_error(iteration, error);
+    }
+
+    fn tracks_diversity(&self) -> bool {
+        (**self).tracks_diversity()
+    }
+
+    fn requested_stop(&self) -> Option<StopReason> {
+        (**self).requested_stop()
+    }
+}
+
+impl<'o, S: Score> Observer<S> for Vec<Box<dyn Observer<S> + 'o>> {
+    fn on_iteration(&mut self, progress: &Progress<S>) {
+        for observer in self {
+            observer.on_iteration(progress);
+        }
+    }
+
+    fn on_restart(&mut self, restart: &Restart) {
+        for observer in self {
+            observer.on_restart(restart);
+        }
+    }
+
+    fn on_checkpoint_error(&mut self, iteration: u32, error: &CheckpointError) {
+        for observer in self {
+            observer.on_checkpoint_error(iteration, error);
+        }
+    }
+
+    fn tracks_diversity(&self) -> bool {
+        self.iter().any(|observer| observer.tracks_diversity())
+    }
+
+    fn requested_stop(&self) -> Option<StopReason> {
+        self.iter().find_map(|observer| observer.requested_stop())
+    }
+}
+
+pub struct ConsoleObserver<W: Write = Stderr> {
+    writer: W,
+    interval: u32,
+    tracks_diversity: bool,
+}
+
+impl ConsoleObserver {
+    pub fn new(interval: u32) -> Self {
+        Self::with_writer(io::stderr(), interval)
+    }
+}
+
+impl<W: Write> ConsoleObserver<W> {
+    pub fn with_writer(writer: W, interval: u32) -> Self {
+        Self {
+            writer,
+            interval: interval.max(1),
+            tracks_diversity: false,
+        }
+    }
+
+    pub fn with_diversity(mut self) -> Self {
+        self.tracks_diversity = true;
+        self
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<S: Score, W: Write> Observer<S> for ConsoleObserver<W> {
+    fn on_iteration(&mut self, progress: &Progress<S>) {
+        if !progress.iteration.is_multiple_of(self.interval) {
+            return;
+        }
+
+        let Progress {
+            iteration,
+            iteration_best_score,
+            best_score,
+            elapsed,
+            diversity,
+            ..
+        } = progress;
+
+        let _ = match diversity {
+            Some(diversity) => writeln!(
+                self.writer,
+                "Iteration {iteration}: best {best_score}, iteration best {iteration_best_score}, diversity {diversity:.3}, elapsed {elapsed:.2?}"
+            ),
+            None => writeln!(
+                self.writer,
+                "Iteration {iteration}: best {best_score}, iteration best {iteration_best_score}, elapsed {elapsed:.2?}"
+            ),
+        };
+    }
+
+    fn on_restart(&mut self, restart: &Restart) {
+        let Restart {
+            iteration,
+            reason,
+            convergence,
+        } = restart;
+
+        let _ = writeln!(
+            self.writer,
+            "Iteration {iteration}: restart ({reason}), branching factor {:.3}, entropy {:.3}, diversity {:.3}",
+            convergence.branching_factor, convergence.entropy, convergence.diversity
+        );
+    }
+
+    fn on_checkpoint_error(&mut self, iteration: u32, error: &CheckpointError) {
+        let _ = writeln!(
+            self.writer,
+            "Iteration {iteration}: unable to save checkpoint: {error:?}"
+        );
+    }
+
+    fn tracks_diversity(&self) -> bool {
+        self.tracks_diversity
+    }
+}
+
+pub struct CsvObserver<W: Write> {
+    writer: W,
+    header_written: bool,
+    tracks_diversity: bool,
+    error: Option<io::Error>,
+}
+
+impl CsvObserver<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> CsvObserver<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            header_written: false,
+            tracks_diversity: false,
+            error: None,
+        }
+    }
+
+    pub fn with_diversity(mut self) -> Self {
+        self.tracks_diversity = true;
+        self
+    }
+
+    pub fn error(&self) -> Option<&io::Error> {
+        self.error.as_ref()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+    fn write_progress<S: Score>(&mut self, progress: &Progress<S>) -> io::Result<()> {
+        if !self.header_written {
+            writeln!(
+                self.writer,
+                "iteration,iteration_best_score,best_score,elapsed_ms,diversity,restarts"
+            )?;
+            self.header_written = true;
+        }
+
+        let diversity = progress
+            .diversity
+            .map_or(String::new(), |diversity| diversity.to_string());
+
+        writeln!(
+            self.writer,
+            "{},{},{},{:.3},{},{}",
+            progress.iteration,
+            progress.iteration_best_score,
+            progress.best_score,
+            progress.elapsed.as_secs_f64() * 1000.0,
+            diversity,
+            progress.restarts
+        )
+    }
+}
+
+impl<S: Score, W: Write> Observer<S> for CsvObserver<W> {
+    fn on_iteration(&mut self, progress: &Progress<S>) {
+        if self.error.is_some() {
+            return;
+        }
+
+        if let Err(error) = self.write_progress(progress) {
+            self.error = Some(error);
+        }
+    }
+
+    fn tracks_diversity(&self) -> bool {
+        self.tracks_diversity
+    }
+}
+
+pub struct HistoryObserver<S: Score> {
+    history: Vec<Progress<S>>,
+    restarts: Vec<Restart>,
+    tracks_diversity: bool,
+}
+
+impl<S: Score> HistoryObserver<S> {
+    pub fn new() -> Self {
+        Self {
+            history: Vec::new(),
+            restarts: Vec::new(),
+            tracks_diversity: false,
+        }
+    }
+
+    pub fn with_diversity(mut self) -> Self {
+        self.tracks_diversity = true;
+        self
+    }
+
+    pub fn history(&self) -> &Vec<Progress<S>> {
+        &self.history
+    }
+
+    pub fn restarts(&self) -> &Vec<Restart> {
+        &self.restarts
+    }
+
+    pub fn into_history(self) -> Vec<Progress<S>> {
+        self.history
+    }
+}
+
+impl<S: Score> Default for HistoryObserver<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Score> Observer<S> for HistoryObserver<S> {
+    fn on_iteration(&mut self, progress: &Progress<S>) {
+        self.history.push(*progress);
+    }
+
+    fn on_restart(&mut self, restart: &Restart) {
+        self.restarts.push(*restart);
+    }
+
+    fn tracks_diversity(&self) -> bool {
+        self.tracks_diversity
+    }
+}
@@ -0,0 +1,528 @@
+use std::collections::VecDeque;
+
+use crate::{instance::Instance, matrix::Matrix, weight::Weight};
+
+pub fn greedy_perfect_matching<I: Instance>(instance: &I, nodes: &[usize]) -> Vec<(usize, usize)> {
+    let nodes_count = nodes.len();
+
+    let mut edges: Vec<(usize, usize)> = Vec::with_capacity(nodes_count * nodes_count / 2);
+    for first in 0..nodes_count {
+        for second in first + 1..nodes_count {
+            edges.push((first, second));
+        }
+    }
+    edges.sort_by(|(first_from, first_to), (second_from, second_to)| {
+        let first = instance.distance(nodes[*first_from], nodes[*first_to]);
+        let second = instance.distance(nodes[*second_from], nodes[*second_to]);
+
+        first.total_cmp(&second)
+    });
+
+    let mut matched = vec![false; nodes_count];
+    let mut matching = Vec::with_capacity(nodes_count / 2);
+
+    for (first, second) in edges {
+        if !matched[first] && !matched[second] {
+            matched[first] = true;
+            matched[second] = true;
+            matching.push((nodes[first], nodes[second]));
+        }
+    }
+
+    matching
+}
+
+pub fn exact_perfect_matching<I: Instance>(instance: &I, nodes: &[usize]) -> Vec<(usize, usize)> {
+    let nodes_count = nodes.len();
+    if nodes_count < 2 {
+        return Vec::new();
+    }
+
+    let weights = Matrix::from_fn(nodes_count, nodes_count, |from, to| {
+        instance.distance(nodes[from], nodes[to]).to_f64()
+    });
+    let weights = quantize_weights(weights);
+
+    let max_weight = weights.as_slice().iter().copied().max().unwrap_or(0);
+
+    // Inverting the weights with a large enough offset turns the minimum weight
+    // perfect matching into the maximum weight matching of the complete graph.
+    let offset = (nodes_count as i64 / 2 + 1) * (max_weight + 1);
+
+    let mut blossom = Blossom::new(nodes_count);
+    for (first, row) in weights.rows().enumerate() {
+        for (second, weight) in row.iter().enumerate() {
+            if first != second {
+                blossom.set_weight(first + 1, second + 1, offset - weight);
+            }
+        }
+    }
+
+    blossom
+        .solve()
+        .into_iter()
+        .map(|(first, second)| (nodes[first], nodes[second]))
+        .collect()
+}
+
+fn quantize_weights(weights: Matrix<f64>) -> Matrix<i64> {
+    const MAX_QUANTIZED_WEIGHT: f64 = (1u64 << 40) as f64;
+
+    let max_weight = weights.as_slice().iter().copied().fold(0.0, f64::max);
+    let integral = weights
+        .as_slice()
+        .iter()
+        .all(|weight| weight.fract() == 0.0);
+
+    let scale = if integral && max_weight <= MAX_QUANTIZED_WEIGHT {
+        1.0
+    } else if max_weight > 0.0 {
+        MAX_QUANTIZED_WEIGHT / max_weight
+    } else {
+        1.0
+    };
+
+    Matrix::from_fn(
+        weights.rows_count(),
+        weights.columns_count(),
+        |row, column| (weights[row][column].max(0.0) * scale).round() as i64,
+    )
+}
+
+#[derive(Clone, Copy, Default)]
+struct Edge {
+    from: usize,
+    to: usize,
+    weight: i64,
+}
+
+struct Blossom {
+    nodes_count: usize,
+    vertices_count: usize,
+    edges: Matrix<Edge>,
+    label: Vec<i64>,
+    mate: Vec<usize>,
+    slack: Vec<usize>,
+    top: Vec<usize>,
+    parent: Vec<usize>,
+    flower_from: Matrix<usize>,
+    side: Vec<i8>,
+    visited: Vec<usize>,
+    visited_stamp: usize,
+    flower: Vec<Vec<usize>>,
+    queue: VecDeque<usize>,
+}
+
+impl Blossom {
+    const UNLABELED: i8 = -1;
+    const OUTER: i8 = 0;
+    const INNER: i8 = 1;
+
+    fn new(nodes_count: usize) -> Self {
+        let size = nodes_count * 2 + 1;
+
+        let edges = Matrix::from_fn(size, size, |from, to| Edge {
+            from,
+            to,
+            ..Edge::default()
+        });
+
+        Self {
+            nodes_count,
+            vertices_count: nodes_count,
+            edges,
+            label: vec![0; size],
+            mate: vec![0; size],
+            slack: vec![0; size],
+            top: vec![0; size],
+            parent: vec![0; size],
+            flower_from: Matrix::new(size, nodes_count + 1, 0),
+            side: vec![0; size],
+            visited: vec![0; size],
+            visited_stamp: 0,
+            flower: vec![Vec::new(); size],
+            queue: VecDeque::new(),
+        }
+    }
+
+    fn set_weight(&mut self, from: usize, to: usize, weight: i64) {
+        self.edges[from][to].weight = weight;
+    }
+
+    fn solve(mut self) -> Vec<(usize, usize)> {
+        let nodes_count = self.nodes_count;
+
+        for vertex in 0..=nodes_count {
+            self.top[vertex] = vertex;
+            self.flower[vertex].clear();
+        }
+
+        let mut max_weight = 0;
+        for from in 1..=nodes_count {
+            for to in 1..=nodes_count {
+                self.flower_from[from][to] = if from == to { from } else { 0 };
+                max_weight = max_weight.max(self.edges[from][to].weight);
+            }
+        }
+        for vertex in 1..=nodes_count {
+            self.label[vertex] = max_weight;
+        }
+
+        while self.augment_matching() {}
+
+        (1..=nodes_count)
+            .filter(|vertex| self.mate[*vertex] != 0 && *vertex < self.mate[*vertex])
+            .map(|vertex| (vertex - 1, self.mate[vertex] - 1))
+            .collect()
+    }
+
+    #[inline]
+    fn edge_delta(&self, edge: Edge) -> i64 {
+        self.label[edge.from] + self.label[edge.to] - self.edges[edge.from][edge.to].weight * 2
+    }
+
+    fn update_slack(&mut self, vertex: usize, blossom: usize) {
+        let slack = self.slack[blossom];
+        if slack == 0
+            || self.edge_delta(self.edges[vertex][blossom])
+                < self.edge_delta(self.edges[slack][blossom])
+        {
+            self.slack[blossom] = vertex;
+        }
+    }
+
+    fn set_slack(&mut self, blossom: usize) {
+        self.slack[blossom] = 0;
+        for vertex in 1..=self.nodes_count {
+            if self.edges[vertex][blossom].weight > 0
+                && self.top[vertex] != blossom
+                && self.side[self.top[vertex]] == Self::OUTER
+            {
+                self.update_slack(vertex, blossom);
+            }
+        }
+    }
+
+    fn queue_push(&mut self, blossom: usize) {
+        if blossom <= self.nodes_count {
+            self.queue.push_back(blossom);
+        } else {
+            for index in 0..self.flower[blossom].len() {
+                self.queue_push(self.flower[blossom][index]);
+            }
+        }
+    }
+
+    fn set_top(&mut self, blossom: usize, top: usize) {
+        self.top[blossom] = top;
+        if blossom > self.nodes_count {
+            for index in 0..self.flower[blossom].len() {
+                self.set_top(self.flower[blossom][index], top);
+            }
+        }
+    }
+
+    fn even_position(&mut self, blossom: usize, sub_blossom: usize) -> usize {
+        let flower = &mut self.flower[blossom];
+        let position = flower
+            .iter()
+            .position(|vertex| *vertex == sub_blossom)
+            .unwrap();
+
+        if position % 2 == 1 {
+            flower[1..].reverse();
+            flower.len() - position
+        } else {
+            position
+        }
+    }
+
+    fn set_mate(&mut self, from: usize, to: usize) {
+        self.mate[from] = self.edges[from][to].to;
+
+        if from > self.nodes_count {
+            let edge = self.edges[from][to];
+            let sub_blossom = self.flower_from[from][edge.from];
+            let position = self.even_position(from, sub_blossom);
+
+            for index in 0..position {
+                let first = self.flower[from][index];
+                let second = self.flower[from][index ^ 1];
+                self.set_mate(first, second);
+            }
+            self.set_mate(sub_blossom, to);
+            self.flower[from].rotate_left(position);
+        }
+    }
+
+    fn augment(&mut self, mut from: usize, mut to: usize) {
+        loop {
+            let next = self.top[self.mate[from]];
+            self.set_mate(from, to);
+            if next == 0 {
+                return;
+            }
+
+            let next_parent = self.top[self.parent[next]];
+            self.set_mate(next, next_parent);
+
+            from = next_parent;
+            to = next;
+        }
+    }
+
+    fn lowest_common_ancestor(&mut self, mut first: usize, mut second: usize) -> usize {
+        self.visited_stamp += 1;
+        let stamp = self.visited_stamp;
+
+        while first != 0 || second != 0 {
+            if first != 0 {
+                if self.visited[first] == stamp {
+                    return first;
+                }
+                self.visited[first] = stamp;
+
+                first = self.top[self.mate[first]];
+                if first != 0 {
+                    first = self.top[self.parent[first]];
+                }
+            }
+            std::mem::swap(&mut first, &mut second);
+        }
+
+        0
+    }
+
+    fn add_blossom(&mut self, from: usize, ancestor: usize, to: usize) {
+        let nodes_count = self.nodes_count;
+
+        let mut blossom = nodes_count + 1;
+        while blossom <= self.vertices_count && self.top[blossom] != 0 {
+            blossom += 1;
+        }
+        if blossom > self.vertices_count {
+            self.vertices_count += 1;
+        }
+
+        self.label[blossom] = 0;
+        self.side[blossom] = Self::OUTER;
+        self.mate[blossom] = self.mate[ancestor];
+        self.flower[blossom].clear();
+        self.flower[blossom].push(ancestor);
+
+        let mut vertex = from;
+        while vertex != ancestor {
+            let mate = self.top[self.mate[vertex]];
+            self.flower[blossom].push(vertex);
+            self.flower[blossom].push(mate);
+            self.queue_push(mate);
+            vertex = self.top[self.parent[mate]];
+        }
+        self.flower[blossom][1..].reverse();
+
+        let mut vertex = to;
+        while vertex != ancestor {
+            let mate = self.top[self.mate[vertex]];
+            self.flower[blossom].push(vertex);
+            self.flower[blossom].push(mate);
+            self.queue_push(mate);
+            vertex = self.top[self.parent[mate]];
+        }
+
+        self.set_top(blossom, blossom);
+
+        for vertex in 1..=self.vertices_count {
+            self.edges[blossom][vertex].weight = 0;
+            self.edges[vertex][blossom].weight = 0;
+        }
+        for vertex in 1..=nodes_count {
+            self.flower_from[blossom][vertex] = 0;
+        }
+
+        for index in 0..self.flower[blossom].len() {
+            let sub_blossom = self.flower[blossom][index];
+
+            for vertex in 1..=self.vertices_count {
+                if self.edges[blossom][vertex].weight == 0
+                    || self.edge_delta(self.edges[sub_blossom][vertex])
+                        < self.edge_delta(self.edges[blossom][vertex])
+                {
+                    self.edges[blossom][vertex] = self.edges[sub_blossom][vertex];
+                    self.edges[vertex][blossom] = self.edges[vertex][sub_blossom];
+                }
+            }
+
+            for vertex in 1..=nodes_count {
+                if self.flower_from[sub_blossom][vertex] != 0 {
+                    self.flower_from[blossom][vertex] = sub_blossom;
+                }
+            }
+        }
+
+        self.set_slack(blossom);
+    }
+
+    fn expand_blossom(&mut self, blossom: usize) {
+        for index in 0..self.flower[blossom].len() {
+            let sub_blossom = self.flower[blossom][index];
+            self.set_top(sub_blossom, sub_blossom);
+        }
+
+        let entry = self.edges[blossom][self.parent[blossom]].from;
+        let sub_blossom = self.flower_from[blossom][entry];
+        let position = self.even_position(blossom, sub_blossom);
+
+        for index in (0..position).step_by(2) {
+            let inner = self.flower[blossom][index];
+            let outer = self.flower[blossom][index + 1];
+
+            self.parent[inner] = self.edges[outer][inner].from;
+            self.side[inner] = Self::INNER;
+            self.side[outer] = Self::OUTER;
+            self.slack[inner] = 0;
+            self.set_slack(outer);
+            self.queue_push(outer);
+        }
+
+        self.side[sub_blossom] = Self::INNER;
+        self.parent[sub_blossom] = self.parent[blossom];
+
+        for index in position + 1..self.flower[blossom].len() {
+            let unlabeled = self.flower[blossom][index];
+            self.side[unlabeled] = Self::UNLABELED;
+            self.set_slack(unlabeled);
+        }
+
+        self.top[blossom] = 0;
+    }
+
+    fn on_found_edge(&mut self, edge: Edge) -> bool {
+        let from = self.top[edge.from];
+        let to = self.top[edge.to];
+
+        if self.side[to] == Self::UNLABELED {
+            self.parent[to] = edge.from;
+            self.side[to] = Self::INNER;
+
+            let mate = self.top[self.mate[to]];
+            self.slack[to] = 0;
+            self.slack[mate] = 0;
+            self.side[mate] = Self::OUTER;
+            self.queue_push(mate);
+        } else if self.side[to] == Self::OUTER {
+            let ancestor = self.lowest_common_ancestor(from, to);
+            if ancestor == 0 {
+                self.augment(from, to);
+                self.augment(to, from);
+                return true;
+            }
+            self.add_blossom(from, ancestor, to);
+        }
+
+        false
+    }
+
+    fn augment_matching(&mut self) -> bool {
+        let nodes_count = self.nodes_count;
+
+        for vertex in 1..=self.vertices_count {
+            self.side[vertex] = Self::UNLABELED;
+            self.slack[vertex] = 0;
+        }
+        self.queue.clear();
+
+        for vertex in 1..=self.vertices_count {
+            if self.top[vertex] == vertex && self.mate[vertex] == 0 {
+                self.parent[vertex] = 0;
+                self.side[vertex] = Self::OUTER;
+                self.queue_push(vertex);
+            }
+        }
+        if self.queue.is_empty() {
+            return false;
+        }
+
+        loop {
+            while let Some(from) = self.queue.pop_front() {
+                if self.side[self.top[from]] == Self::INNER {
+                    continue;
+                }
+
+                for to in 1..=nodes_count {
+                    let edge = self.edges[from][to];
+                    if edge.weight > 0 && self.top[from] != self.top[to] {
+                        if self.edge_delta(edge) == 0 {
+                            if self.on_found_edge(edge) {
+                                return true;
+                            }
+                        } else {
+                            self.update_slack(from, self.top[to]);
+                        }
+                    }
+                }
+            }
+
+            let mut delta = i64::MAX;
+            for blossom in nodes_count + 1..=self.vertices_count {
+                if self.top[blossom] == blossom && self.side[blossom] == Self::INNER {
+                    delta = delta.min(self.label[blossom] / 2);
+                }
+            }
+            for vertex in 1..=self.vertices_count {
+                let slack = self.slack[vertex];
+                if self.top[vertex] == vertex && slack != 0 {
+                    let edge_delta = self.edge_delta(self.edges[slack][vertex]);
+                    if self.side[vertex] == Self::UNLABELED {
+                        delta = delta.min(edge_delta);
+                    } else if self.side[vertex] == Self::OUTER {
+                        delta = delta.min(edge_delta / 2);
+                    }
+                }
+            }
+
+            for vertex in 1..=nodes_count {
+                match self.side[self.top[vertex]] {
+                    Self::OUTER => {
+                        if self.label[vertex] <= delta {
+                            return false;
+                        }
+                        self.label[vertex] -= delta;
+                    }
+                    Self::INNER => self.label[vertex] += delta,
+                    _ => {}
+                }
+            }
+            for blossom in nodes_count + 1..=self.vertices_count {
+                if self.top[blossom] == blossom {
+                    match self.side[blossom] {
+                        Self::OUTER => self.label[blossom] += delta * 2,
+                        Self::INNER => self.label[blossom] -= delta * 2,
+                        _ => {}
+                    }
+                }
+            }
+
+            self.queue.clear();
+            for vertex in 1..=self.vertices_count {
+                let slack = self.slack[vertex];
+                if self.top[vertex] == vertex
+                    && slack != 0
+                    && self.top[slack] != vertex
+                    && self.edge_delta(self.edges[slack][vertex]) == 0
+                    && self.on_found_edge(self.edges[slack][vertex])
+                {
+                    return true;
+                }
+            }
+
+            for blossom in nodes_count + 1..=self.vertices_count {
+                if self.top[blossom] == blossom
+                    && self.side[blossom] == Self::INNER
+                    && self.label[blossom] == 0
+                {
+                    self.expand_blossom(blossom);
+                }
+            }
+        }
+    }
+}
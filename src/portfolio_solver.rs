@@ -0,0 +1,321 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    instance::{Instance, ScoreOf},
+    models::{AdjMatrix, Solution, SolveError, Solver, Way},
+    observer::{Observer, Progress},
+    termination::StopReason,
+    weight::Score,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Racing {
+    interval: Duration,
+    tolerance: f64,
+}
+
+#[derive(Debug)]
+pub struct PortfolioEntry<S: Score> {
+    pub name: String,
+    pub score: Option<S>,
+    pub stop_reason: Option<StopReason>,
+    pub elapsed: Duration,
+    pub error: Option<SolveError>,
+}
+
+pub struct PortfolioSolution<'a, I: Instance = AdjMatrix<u32>> {
+    best_way: Option<Way<'a, I>>,
+    stop_reason: StopReason,
+    entries: Vec<PortfolioEntry<ScoreOf<I>>>,
+}
+
+impl<'a, I: Instance> PortfolioSolution<'a, I> {
+    pub fn best_way(&self) -> Option<&Way<'a, I>> {
+        self.best_way.as_ref()
+    }
+
+    pub fn stop_reason(&self) -> StopReason {
+        self.stop_reason
+    }
+
+    pub fn entries(&self) -> &Vec<PortfolioEntry<ScoreOf<I>>> {
+        &self.entries
+    }
+
+    pub fn into_best_way(self) -> Option<Way<'a, I>> {
+        self.best_way
+    }
+}
+
+pub struct PortfolioSolver<I: Instance = AdjMatrix<u32>> {
+    solvers: Vec<(String, Box<dyn Solver<I> + Sync>)>,
+    time_limit: Duration,
+    racing: Option<Racing>,
+}
+
+impl<I: Instance> PortfolioSolver<I> {
+    pub fn new(time_limit: Duration) -> Self {
+        Self {
+            solvers: Vec::new(),
+            time_limit,
+            racing: None,
+        }
+    }
+
+    pub fn with_solver(
+        mut self,
+        name: impl Into<String>,
+        solver: impl Solver<I> + Sync + 'static,
+    ) -> Self {
+        self.solvers.push((name.into(), Box::new(solver)));
+        self
+    }
+
+    pub fn with_boxed_solver(
+        mut self,
+        name: impl Into<String>,
+        solver: Box<dyn Solver<I> + Sync>,
+    ) -> Self {
+        self.solvers.push((name.into(), solver));
+        self
+    }
+
+    pub fn with_racing(mut self, interval: Duration, tolerance: f64) -> Self {
+        self.racing = Some(Racing {
+            interval,
+            tolerance,
+        });
+        self
+    }
+
+    pub fn solve_portfolio<'a>(&self, instance: &'a I) -> PortfolioSolution<'a, I> {
+        self.solve_portfolio_with_observer(instance, &mut ())
+    }
+
+    pub fn solve_portfolio_with_observer<'a>(
+        &self,
+        instance: &'a I,
+        observer: &mut dyn Observer<ScoreOf<I>>,
+    ) -> PortfolioSolution<'a, I> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+        let race = Race::new(self.solvers.len(), self.time_limit);
+
+        let results: Vec<(Result<Solution<'a, I>, SolveError>, Duration)> =
+            thread::scope(|scope| {
+                let race = &race;
+                let handles: Vec<_> = self
+                    .solvers
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (_, solver))| {
+                        scope.spawn(move || {
+                            let start = Instant::now();
+                            let mut observer = RaceObserver { race, index };
+                            let result = solver.try_solve_with_observer(instance, &mut observer);
+
+                            (result, start.elapsed())
+                        })
+                    })
+                    .collect();
+
+                let racing_interval = self.racing.map(|racing| racing.interval.max(POLL_INTERVAL));
+                let mut next_round = racing_interval;
+
+                while !handles.iter().all(|handle| handle.is_finished()) {
+                    thread::sleep(POLL_INTERVAL);
+
+                    if let Some(stop_reason) = observer.requested_stop() {
+                        race.stop(stop_reason);
+                    }
+
+                    if let (Some(racing), Some(round)) = (&self.racing, next_round) {
+                        if race.start.elapsed() >= round {
+                            race.eliminate_lagging(racing.tolerance);
+                            next_round = Some(round + racing.interval.max(POLL_INTERVAL));
+                        }
+                    }
+                }
+
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .collect()
+            });
+
+        let mut best_way: Option<Way<'a, I>> = None;
+        let mut entries = Vec::with_capacity(results.len());
+
+        for ((name, _), (result, elapsed)) in self.solvers.iter().zip(results) {
+            let entry = match result {
+                Ok(solution) => {
+                    let entry = PortfolioEntry {
+                        name: name.clone(),
+                        score: Some(solution.way().score()),
+                        stop_reason: Some(solution.stop_reason()),
+                        elapsed,
+                        error: None,
+                    };
+
+                    let way = solution.into_way();
+                    if best_way.as_ref().is_none_or(|best_way| way < *best_way) {
+                        best_way = Some(way);
+                    }
+
+                    entry
+                }
+                Err(error) => PortfolioEntry {
+                    name: name.clone(),
+                    score: None,
+                    stop_reason: None,
+                    elapsed,
+                    error: Some(error),
+                },
+            };
+
+            entries.push(entry);
+        }
+
+        let stop_reason = race.stop_reason(&entries);
+
+        PortfolioSolution {
+            best_way,
+            stop_reason,
+            entries,
+        }
+    }
+}
+
+impl<I: Instance> Solver<I> for PortfolioSolver<I> {
+    fn solve<'a>(&self, instance: &'a I) -> Way<'a, I> {
+        self.try_solve(instance).unwrap()
+    }
+
+    fn try_solve<'a>(&self, instance: &'a I) -> Result<Way<'a, I>, SolveError> {
+        self.solve_portfolio(instance)
+            .into_best_way()
+            .ok_or(SolveError::NoSolution)
+    }
+
+    fn try_solve_with_observer<'a>(
+        &self,
+        instance: &'a I,
+        observer: &mut dyn Observer<ScoreOf<I>>,
+    ) -> Result<Solution<'a, I>, SolveError> {
+        let start = Instant::now();
+        let PortfolioSolution {
+            best_way,
+            stop_reason,
+            entries,
+        } = self.solve_portfolio_with_observer(instance, observer);
+        let way = best_way.ok_or(SolveError::NoSolution)?;
+
+        observer.on_iteration(&Progress {
+            iteration: 1,
+            iteration_best_score: way.score(),
+            best_score: way.score(),
+            elapsed: start.elapsed(),
+            diversity: None,
+            restarts: 0,
+        });
+
+        Ok(Solution::new(way, stop_reason).with_portfolio_entries(entries))
+    }
+}
+
+struct Race {
+    start: Instant,
+    time_limit: Duration,
+    best_scores: Mutex<Vec<Option<f64>>>,
+    eliminated: Vec<AtomicBool>,
+    stopped: OnceLock<StopReason>,
+}
+
+impl Race {
+    fn new(solvers_count: usize, time_limit: Duration) -> Self {
+        Self {
+            start: Instant::now(),
+            time_limit,
+            best_scores: Mutex::new(vec![None; solvers_count]),
+            eliminated: (0..solvers_count).map(|_| AtomicBool::new(false)).collect(),
+            stopped: OnceLock::new(),
+        }
+    }
+
+    fn stop(&self, stop_reason: StopReason) {
+        let _ = self.stopped.set(stop_reason);
+    }
+
+    fn stop_reason<S: Score>(&self, entries: &[PortfolioEntry<S>]) -> StopReason {
+        let member_stopped_by = |reason| {
+            entries
+                .iter()
+                .any(|entry| entry.stop_reason == Some(reason))
+        };
+
+        if let Some(stop_reason) = self.stopped.get() {
+            *stop_reason
+        } else if member_stopped_by(StopReason::Interrupted) {
+            StopReason::Interrupted
+        } else if member_stopped_by(StopReason::TimeLimit) || self.is_out_of_time() {
+            StopReason::TimeLimit
+        } else {
+            StopReason::Completed
+        }
+    }
+
+    fn is_out_of_time(&self) -> bool {
+        self.start.elapsed() >= self.time_limit
+    }
+
+    fn eliminate_lagging(&self, tolerance: f64) {
+        let best_scores = self.best_scores.lock().unwrap();
+
+        let leader_score = best_scores
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !self.eliminated[*index].load(Ordering::Relaxed))
+            .filter_map(|(_, score)| *score)
+            .min_by(f64::total_cmp);
+
+        let Some(leader_score) = leader_score else {
+            return;
+        };
+
+        for (index, score) in best_scores.iter().enumerate() {
+            if score.is_some_and(|score| score > leader_score * (1.0 + tolerance)) {
+                self.eliminated[index].store(true, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+struct RaceObserver<'r> {
+    race: &'r Race,
+    index: usize,
+}
+
+impl<S: Score> Observer<S> for RaceObserver<'_> {
+    fn on_iteration(&mut self, progress: &Progress<S>) {
+        self.race.best_scores.lock().unwrap()[self.index] = Some(progress.best_score.to_f64());
+    }
+
+    fn requested_stop(&self) -> Option<StopReason> {
+        if let Some(stop_reason) = self.race.stopped.get() {
+            Some(*stop_reason)
+        } else if self.race.eliminated[self.index].load(Ordering::Relaxed) {
+            Some(StopReason::Eliminated)
+        } else if self.race.is_out_of_time() {
+            Some(StopReason::TimeLimit)
+        } else {
+            None
+        }
+    }
+}
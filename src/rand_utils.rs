@@ -1,6 +1,9 @@
 use std::ops::{Add, Mul};
 
-use rand::{distributions::Distribution, rngs::StdRng, Rng, SeedableRng};
+use rand::{distributions::Distribution, rngs::StdRng, Error, Rng, RngCore, SeedableRng};
+use rand_pcg::Pcg64;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use serde::{Deserialize, Serialize};
 
 pub trait RngDistributionExt {
     #[allow(dead_code)]
@@ -16,9 +19,25 @@ pub trait RngDistributionExt {
         distribution: D,
         selector: F,
     ) -> Option<T>;
+
+    fn distribute_by_key_with_buffer<
+        T,
+        I: Iterator<Item = T>,
+        F: Fn(&T) -> G,
+        G: Default + Copy + PartialOrd + Add<Output = G> + Mul<Output = G>,
+        D: Distribution<G>,
+    >(
+        &mut self,
+        elements: I,
+        distribution: D,
+        selector: F,
+        buffer: &mut Vec<(G, T)>,
+    ) -> Option<T>;
+
+    fn uniform_below(&mut self, bound: u64) -> u64;
 }
 
-impl RngDistributionExt for StdRng {
+impl<R: Rng + ?Sized> RngDistributionExt for R {
     fn distribute_by_key<
         T,
         I: Iterator<Item = T>,
@@ -31,36 +50,153 @@ impl RngDistributionExt for StdRng {
         distribution: D,
         selector: F,
     ) -> Option<T> {
+        self.distribute_by_key_with_buffer(elements, distribution, selector, &mut Vec::new())
+    }
+
+    fn distribute_by_key_with_buffer<
+        T,
+        I: Iterator<Item = T>,
+        F: Fn(&T) -> G,
+        G: Default + Copy + PartialOrd + Add<Output = G> + Mul<Output = G>,
+        D: Distribution<G>,
+    >(
+        &mut self,
+        elements: I,
+        distribution: D,
+        selector: F,
+        buffer: &mut Vec<(G, T)>,
+    ) -> Option<T> {
+        buffer.clear();
+
         let mut probability_acc = Default::default();
-        let pairs: Vec<(G, T)> = elements
-            .map(|element| {
-                let probability = selector(&element);
-                probability_acc = probability_acc + probability;
+        buffer.extend(elements.map(|element| {
+            let probability = selector(&element);
+            probability_acc = probability_acc + probability;
 
-                (probability_acc, element)
-            })
-            .collect();
+            (probability_acc, element)
+        }));
 
-        let size = pairs.len();
+        let size = buffer.len();
         if size < 1 {
             return None;
         }
 
         let random_value = self.sample(distribution) * probability_acc;
-        for (index, (probability, element)) in pairs.into_iter().enumerate() {
-            if random_value < probability || index == size - 1 {
-                return Some(element);
+        let index = buffer
+            .partition_point(|(probability, _)| *probability <= random_value)
+            .min(size - 1);
+
+        Some(buffer.swap_remove(index).1)
+    }
+
+    fn uniform_below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "cannot sample from an empty range");
+
+        let mut product = u128::from(self.next_u64()) * u128::from(bound);
+        if (product as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (product as u64) < threshold {
+                product = u128::from(self.next_u64()) * u128::from(bound);
             }
         }
 
-        None
+        (product >> 64) as u64
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnitInterval;
+
+impl Distribution<f64> for UnitInterval {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        const SCALE: f64 = 1.0 / (1u64 << f64::MANTISSA_DIGITS) as f64;
+
+        (rng.next_u64() >> (u64::BITS - f64::MANTISSA_DIGITS)) as f64 * SCALE
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RngAlgorithm {
+    Std,
+    #[default]
+    Pcg64,
+    Xoshiro256PlusPlus,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum RandomProvider {
+    #[serde(skip)]
+    Std(StdRng),
+    Pcg64(Pcg64),
+    Xoshiro256PlusPlus(Xoshiro256PlusPlus),
+}
+
+impl RandomProvider {
+    pub fn algorithm(&self) -> RngAlgorithm {
+        match self {
+            RandomProvider::Std(_) => RngAlgorithm::Std,
+            RandomProvider::Pcg64(_) => RngAlgorithm::Pcg64,
+            RandomProvider::Xoshiro256PlusPlus(_) => RngAlgorithm::Xoshiro256PlusPlus,
+        }
+    }
+}
+
+impl RngCore for RandomProvider {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            RandomProvider::Std(random_provider) => random_provider.next_u32(),
+            RandomProvider::Pcg64(random_provider) => random_provider.next_u32(),
+            RandomProvider::Xoshiro256PlusPlus(random_provider) => random_provider.next_u32(),
+        }
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            RandomProvider::Std(random_provider) => random_provider.next_u64(),
+            RandomProvider::Pcg64(random_provider) => random_provider.next_u64(),
+            RandomProvider::Xoshiro256PlusPlus(random_provider) => random_provider.next_u64(),
+        }
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            RandomProvider::Std(random_provider) => random_provider.fill_bytes(dest),
+            RandomProvider::Pcg64(random_provider) => random_provider.fill_bytes(dest),
+            RandomProvider::Xoshiro256PlusPlus(random_provider) => random_provider.fill_bytes(dest),
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match self {
+            RandomProvider::Std(random_provider) => random_provider.try_fill_bytes(dest),
+            RandomProvider::Pcg64(random_provider) => random_provider.try_fill_bytes(dest),
+            RandomProvider::Xoshiro256PlusPlus(random_provider) => {
+                random_provider.try_fill_bytes(dest)
+            }
+        }
+    }
+}
+
+pub fn random_provider(algorithm: RngAlgorithm, random_seed: Option<u64>) -> RandomProvider {
+    match algorithm {
+        RngAlgorithm::Std => RandomProvider::Std(seeded(random_seed)),
+        RngAlgorithm::Pcg64 => RandomProvider::Pcg64(seeded(random_seed)),
+        RngAlgorithm::Xoshiro256PlusPlus => RandomProvider::Xoshiro256PlusPlus(seeded(random_seed)),
     }
 }
 
-pub fn random_provider(random_seed: Option<u64>) -> StdRng {
+#[inline]
+fn seeded<R: SeedableRng>(random_seed: Option<u64>) -> R {
     if let Some(seed) = random_seed {
-        StdRng::seed_from_u64(seed)
+        R::seed_from_u64(seed)
     } else {
-        StdRng::from_entropy()
+        R::from_entropy()
     }
 }
@@ -0,0 +1,611 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr, thread, time::Duration};
+
+use crate::{
+    ant_q_solver::{AntQSolver, InitialPheromone},
+    cancellation::CancellationToken,
+    candidate_lists::CandidateStrategy,
+    christofides_solver::{ChristofidesSolver, MatchingAlgorithm},
+    convergence::{RestartCriterion, RestartStrategy},
+    greedy_solver::GreedySolver,
+    instance::Instance,
+    models::{AdjMatrix, Solver},
+    portfolio_solver::PortfolioSolver,
+    rand_utils::RngAlgorithm,
+    termination::Termination,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    UnknownSolver(String),
+    UnknownParameter {
+        solver: String,
+        parameter: String,
+    },
+    InvalidValue {
+        parameter: String,
+        value: String,
+    },
+    MissingParameter(String),
+    UnsupportedRngAlgorithm {
+        parameter: String,
+        rng_algorithm: RngAlgorithm,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParameterKind {
+    Integer,
+    Real,
+    Text,
+    Choice(&'static [&'static str]),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParameterSpec {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub kind: ParameterKind,
+    pub default: Option<&'static str>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Arguments {
+    values: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BuildContext {
+    pub rng_algorithm: RngAlgorithm,
+    pub random_seed: Option<u64>,
+    pub cancellation_token: CancellationToken,
+}
+
+pub type BoxedSolver<I> = Box<dyn Solver<I> + Sync>;
+
+pub type SolverBuilder<I> = fn(&Arguments, &BuildContext) -> Result<BoxedSolver<I>, RegistryError>;
+
+pub struct SolverEntry<I: Instance = AdjMatrix<u32>> {
+    name: &'static str,
+    description: &'static str,
+    parameters: Vec<ParameterSpec>,
+    builder: SolverBuilder<I>,
+}
+
+pub struct SolverRegistry<I: Instance = AdjMatrix<u32>> {
+    entries: Vec<SolverEntry<I>>,
+}
+
+impl ParameterKind {
+    pub fn validate(&self, value: &str) -> bool {
+        match self {
+            ParameterKind::Integer => value.parse::<u64>().is_ok(),
+            ParameterKind::Real => value.parse::<f64>().is_ok(),
+            ParameterKind::Text => true,
+            ParameterKind::Choice(choices) => choices.contains(&value),
+        }
+    }
+}
+
+impl Display for ParameterKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterKind::Integer => write!(f, "integer"),
+            ParameterKind::Real => write!(f, "real"),
+            ParameterKind::Text => write!(f, "text"),
+            ParameterKind::Choice(choices) => write!(f, "{}", choices.join("|")),
+        }
+    }
+}
+
+impl ParameterSpec {
+    pub const fn new(name: &'static str, description: &'static str, kind: ParameterKind) -> Self {
+        Self {
+            name,
+            description,
+            kind,
+            default: None,
+        }
+    }
+
+    pub const fn with_default(mut self, default: &'static str) -> Self {
+        self.default = Some(default);
+        self
+    }
+}
+
+impl Arguments {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, RegistryError> {
+        match self.get(name) {
+            Some(value) => match value.parse() {
+                Ok(value) => Ok(Some(value)),
+                _ => Err(RegistryError::InvalidValue {
+                    parameter: name.to_string(),
+                    value: value.to_string(),
+                }),
+            },
+            None => Ok(None),
+        }
+    }
+
+    pub fn required<T: FromStr>(&self, name: &str) -> Result<T, RegistryError> {
+        self.value(name)?
+            .ok_or_else(|| RegistryError::MissingParameter(name.to_string()))
+    }
+
+    pub fn duration(&self, name: &str) -> Result<Option<Duration>, RegistryError> {
+        match self.value::<f64>(name)? {
+            Some(seconds) => Duration::try_from_secs_f64(seconds).map(Some).map_err(|_| {
+                RegistryError::InvalidValue {
+                    parameter: name.to_string(),
+                    value: seconds.to_string(),
+                }
+            }),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<I: Instance> SolverEntry<I> {
+    pub fn new(name: &'static str, description: &'static str, builder: SolverBuilder<I>) -> Self {
+        Self {
+            name,
+            description,
+            parameters: Vec::new(),
+            builder,
+        }
+    }
+
+    pub fn with_parameter(mut self, parameter: ParameterSpec) -> Self {
+        self.parameters.push(parameter);
+        self
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    pub fn parameters(&self) -> &Vec<ParameterSpec> {
+        &self.parameters
+    }
+
+    pub fn resolve(&self, arguments: &Arguments) -> Result<Arguments, RegistryError> {
+        if let Some((name, _)) = arguments
+            .iter()
+            .find(|(name, _)| !self.parameters.iter().any(|spec| spec.name == *name))
+        {
+            return Err(RegistryError::UnknownParameter {
+                solver: self.name.to_string(),
+                parameter: name.to_string(),
+            });
+        }
+
+        let mut resolved = Arguments::new();
+        for spec in &self.parameters {
+            let Some(value) = arguments.get(spec.name).or(spec.default) else {
+                continue;
+            };
+
+            if !spec.kind.validate(value) {
+                return Err(RegistryError::InvalidValue {
+                    parameter: spec.name.to_string(),
+                    value: value.to_string(),
+                });
+            }
+
+            resolved.insert(spec.name, value);
+        }
+
+        Ok(resolved)
+    }
+
+    pub fn build(
+        &self,
+        arguments: &Arguments,
+        context: &BuildContext,
+    ) -> Result<BoxedSolver<I>, RegistryError> {
+        (self.builder)(&self.resolve(arguments)?, context)
+    }
+}
+
+impl<I: Instance> SolverRegistry<I> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn with_entry(mut self, entry: SolverEntry<I>) -> Self {
+        self.register(entry);
+        self
+    }
+
+    pub fn register(&mut self, entry: SolverEntry<I>) {
+        self.entries.retain(|existing| existing.name != entry.name);
+        self.entries.push(entry);
+    }
+
+    pub fn entries(&self) -> &Vec<SolverEntry<I>> {
+        &self.entries
+    }
+
+    pub fn find(&self, name: &str) -> Option<&SolverEntry<I>> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    pub fn build(
+        &self,
+        name: &str,
+        arguments: &Arguments,
+        context: &BuildContext,
+    ) -> Result<BoxedSolver<I>, RegistryError> {
+        self.find(name)
+            .ok_or_else(|| RegistryError::UnknownSolver(name.to_string()))?
+            .build(arguments, context)
+    }
+
+    pub fn help(&self) -> String {
+        let mut help = String::from("Solvers:\n");
+
+        for entry in &self.entries {
+            help.push_str(&format!("\n  {}: {}\n", entry.name, entry.description));
+
+            for spec in &entry.parameters {
+                const FLAG_WIDTH: usize = 40;
+
+                let flag = format!("--{} <{}>", spec.name, spec.kind);
+                if flag.len() < FLAG_WIDTH {
+                    help.push_str(&format!("    {flag:<FLAG_WIDTH$} {}", spec.description));
+                } else {
+                    help.push_str(&format!(
+                        "    {flag}\n    {:<FLAG_WIDTH$} {}",
+                        "", spec.description
+                    ));
+                }
+                if let Some(default) = spec.default {
+                    help.push_str(&format!(" (default: {default})"));
+                }
+                help.push('\n');
+            }
+        }
+
+        help
+    }
+}
+
+impl<I: Instance + 'static> Default for SolverRegistry<I> {
+    fn default() -> Self {
+        SolverRegistry::new()
+            .with_entry(SolverEntry::new(
+                "greedy",
+                "Nearest neighbor tour construction",
+                build_greedy_solver,
+            ))
+            .with_entry(ant_q_entry())
+            .with_entry(
+                SolverEntry::new(
+                    "christofides",
+                    "Christofides approximation for metric instances",
+                    build_christofides_solver,
+                )
+                .with_parameter(
+                    ParameterSpec::new(
+                        "matching",
+                        "Perfect matching algorithm",
+                        ParameterKind::Choice(&["exact", "greedy"]),
+                    )
+                    .with_default("exact"),
+                ),
+            )
+            .with_entry(portfolio_entry())
+    }
+}
+
+fn portfolio_entry<I: Instance + 'static>() -> SolverEntry<I> {
+    use ParameterKind::{Real, Text};
+
+    let parameters = [
+        ParameterSpec::new(
+            "solvers",
+            "Comma-separated solvers run in parallel, each optionally followed by :parameter=value",
+            Text,
+        )
+        .with_default("greedy,ant-q"),
+        ParameterSpec::new("time-limit", "Shared time budget in seconds", Real).with_default("60"),
+        ParameterSpec::new(
+            "racing-interval",
+            "Seconds between eliminations of lagging solvers",
+            Real,
+        ),
+        ParameterSpec::new(
+            "racing-tolerance",
+            "Relative gap to the leader that eliminates a solver",
+            Real,
+        )
+        .with_default("0.05"),
+    ];
+
+    parameters.into_iter().fold(
+        SolverEntry::new(
+            "portfolio",
+            "Run several solvers in parallel and keep the best tour",
+            build_portfolio_solver,
+        ),
+        SolverEntry::with_parameter,
+    )
+}
+
+fn ant_q_entry<I: Instance>() -> SolverEntry<I> {
+    use ParameterKind::{Choice, Integer, Real, Text};
+
+    let parameters = [
+        ParameterSpec::new("max-iterations", "Max iterations", Integer).with_default("1000"),
+        ParameterSpec::new("population-size", "Population size", Integer).with_default("10"),
+        ParameterSpec::new("threads", "Threads count (0 for all cores)", Integer).with_default("1"),
+        ParameterSpec::new("pheromone-importance", "Pheromone importance", Real)
+            .with_default("1.0"),
+        ParameterSpec::new("destination-importance", "Destination importance", Real)
+            .with_default("2.0"),
+        ParameterSpec::new("pheromone-intensity", "Pheromone intensity", Real).with_default("1.0"),
+        ParameterSpec::new("pheromone-evaporation", "Pheromone evaporation", Real)
+            .with_default("0.1"),
+        ParameterSpec::new("time-limit", "Time limit in seconds", Real),
+        ParameterSpec::new("target-score", "Target score", Real),
+        ParameterSpec::new(
+            "max-stagnant-iterations",
+            "Max iterations without improvement",
+            Integer,
+        ),
+        ParameterSpec::new("max-evaluations", "Max tour evaluations", Integer),
+        ParameterSpec::new("checkpoint", "Checkpoint path", Text),
+        ParameterSpec::new("checkpoint-interval", "Checkpoint interval", Integer)
+            .with_default("100"),
+        ParameterSpec::new("resume-from", "Checkpoint to resume from", Text),
+        ParameterSpec::new(
+            "initial-pheromone",
+            "Pheromone initialization",
+            Choice(&["constant", "tour-length"]),
+        )
+        .with_default("constant"),
+        ParameterSpec::new(
+            "initial-pheromone-value",
+            "Constant initial pheromone",
+            Real,
+        )
+        .with_default("1.0"),
+        ParameterSpec::new(
+            "restart-criterion",
+            "Restart criterion",
+            Choice(&[
+                "none",
+                "branching-factor",
+                "entropy",
+                "diversity",
+                "stagnation",
+            ]),
+        )
+        .with_default("none"),
+        ParameterSpec::new(
+            "restart-threshold",
+            "Restart threshold for branching factor, entropy and diversity",
+            Real,
+        ),
+        ParameterSpec::new(
+            "restart-iterations",
+            "Iterations without improvement before a stagnation restart",
+            Integer,
+        ),
+        ParameterSpec::new(
+            "restart-ratio",
+            "Pheromone reinitialization ratio (1 for full restart)",
+            Real,
+        )
+        .with_default("1.0"),
+        ParameterSpec::new(
+            "candidate-list-size",
+            "Candidate list size (0 to disable)",
+            Integer,
+        )
+        .with_default("0"),
+        ParameterSpec::new(
+            "candidate-strategy",
+            "Candidate strategy",
+            Choice(&["nearest", "quadrant", "alpha-nearness"]),
+        )
+        .with_default("nearest"),
+    ];
+
+    parameters.into_iter().fold(
+        SolverEntry::new("ant-q", "Ant-Q colony optimization", build_ant_q_solver),
+        SolverEntry::with_parameter,
+    )
+}
+
+fn build_portfolio_solver<I: Instance + 'static>(
+    arguments: &Arguments,
+    context: &BuildContext,
+) -> Result<BoxedSolver<I>, RegistryError> {
+    let time_limit = arguments
+        .duration("time-limit")?
+        .ok_or_else(|| RegistryError::MissingParameter("time-limit".to_string()))?;
+    let mut solver = PortfolioSolver::new(time_limit);
+
+    if let Some(interval) = arguments.duration("racing-interval")? {
+        solver = solver.with_racing(interval, arguments.required("racing-tolerance")?);
+    }
+
+    let registry = SolverRegistry::<I>::default();
+    let members = arguments.get("solvers").unwrap_or_default();
+    let invalid_members = || RegistryError::InvalidValue {
+        parameter: "solvers".to_string(),
+        value: members.to_string(),
+    };
+
+    for (index, member) in members.split(',').map(str::trim).enumerate() {
+        let mut parts = member.split(':').map(str::trim);
+        let name = parts.next().unwrap_or_default();
+        if name == "portfolio" || name.is_empty() {
+            return Err(invalid_members());
+        }
+
+        let mut member_arguments = Arguments::new();
+        for parameter in parts {
+            let (parameter, value) = parameter.split_once('=').ok_or_else(invalid_members)?;
+            member_arguments.insert(parameter.trim(), value.trim());
+        }
+
+        let member_context = BuildContext {
+            random_seed: context
+                .random_seed
+                .map(|seed| seed.wrapping_add(index as u64)),
+            ..context.clone()
+        };
+        let member_solver = registry.build(name, &member_arguments, &member_context)?;
+        solver = solver.with_boxed_solver(member, member_solver);
+    }
+
+    Ok(Box::new(solver))
+}
+
+fn build_greedy_solver<I: Instance>(
+    _arguments: &Arguments,
+    _context: &BuildContext,
+) -> Result<BoxedSolver<I>, RegistryError> {
+    Ok(Box::new(GreedySolver {}))
+}
+
+fn build_christofides_solver<I: Instance>(
+    arguments: &Arguments,
+    _context: &BuildContext,
+) -> Result<BoxedSolver<I>, RegistryError> {
+    let matching_algorithm = match arguments.get("matching") {
+        Some("greedy") => MatchingAlgorithm::Greedy,
+        _ => MatchingAlgorithm::Exact,
+    };
+
+    Ok(Box::new(ChristofidesSolver::new(matching_algorithm)))
+}
+
+fn build_ant_q_solver<I: Instance>(
+    arguments: &Arguments,
+    context: &BuildContext,
+) -> Result<BoxedSolver<I>, RegistryError> {
+    let threads_count = match arguments.required("threads")? {
+        0 => thread::available_parallelism().map_or(1, |count| count.get()),
+        number => number,
+    };
+
+    let mut solver = AntQSolver::new(
+        arguments.required("max-iterations")?,
+        arguments.required("population-size")?,
+        context.random_seed,
+        arguments.required("pheromone-importance")?,
+        arguments.required("destination-importance")?,
+        arguments.required("pheromone-intensity")?,
+        arguments.required("pheromone-evaporation")?,
+    )
+    .with_threads_count(threads_count)
+    .with_rng_algorithm(context.rng_algorithm)
+    .with_termination(Termination::Cancelled(context.cancellation_token.clone()));
+
+    if let Some(time_limit) = arguments.duration("time-limit")? {
+        solver = solver.with_termination(Termination::TimeLimit(time_limit));
+    }
+
+    if let Some(target_score) = arguments.value("target-score")? {
+        solver = solver.with_termination(Termination::TargetScore(target_score));
+    }
+
+    if let Some(stagnant_iterations) = arguments.value("max-stagnant-iterations")? {
+        solver = solver.with_termination(Termination::MaxStagnantIterations(stagnant_iterations));
+    }
+
+    if let Some(evaluations) = arguments.value("max-evaluations")? {
+        solver = solver.with_termination(Termination::MaxEvaluations(evaluations));
+    }
+
+    for parameter in ["checkpoint", "resume-from"] {
+        if arguments.get(parameter).is_some() && context.rng_algorithm == RngAlgorithm::Std {
+            return Err(RegistryError::UnsupportedRngAlgorithm {
+                parameter: parameter.to_string(),
+                rng_algorithm: context.rng_algorithm,
+            });
+        }
+    }
+
+    if let Some(path) = arguments.get("checkpoint") {
+        solver = solver.with_checkpoints(path, arguments.required("checkpoint-interval")?);
+    }
+
+    if let Some(path) = arguments.get("resume-from") {
+        solver = solver.with_resume_from(path);
+    }
+
+    let initial_pheromone = match arguments.get("initial-pheromone") {
+        Some("tour-length") => InitialPheromone::TourLength,
+        _ => InitialPheromone::Constant(arguments.required("initial-pheromone-value")?),
+    };
+    solver = solver.with_initial_pheromone(initial_pheromone);
+
+    let restart_criterion = match arguments.get("restart-criterion") {
+        Some("branching-factor") => Some(RestartCriterion::BranchingFactor(
+            arguments.required("restart-threshold")?,
+        )),
+        Some("entropy") => Some(RestartCriterion::Entropy(
+            arguments.required("restart-threshold")?,
+        )),
+        Some("diversity") => Some(RestartCriterion::Diversity(
+            arguments.required("restart-threshold")?,
+        )),
+        Some("stagnation") => Some(RestartCriterion::StagnantIterations(
+            arguments.required("restart-iterations")?,
+        )),
+        _ => None,
+    };
+
+    if let Some(criterion) = restart_criterion {
+        let strategy = match arguments.required("restart-ratio")? {
+            ratio if ratio >= 1.0 => RestartStrategy::Full,
+            ratio => RestartStrategy::Partial(ratio),
+        };
+        solver = solver.with_restarts(criterion, strategy);
+    }
+
+    let candidate_list_size = arguments.required("candidate-list-size")?;
+    if candidate_list_size > 0 {
+        let strategy = match arguments.get("candidate-strategy") {
+            Some("quadrant") => CandidateStrategy::Quadrant,
+            Some("alpha-nearness") => CandidateStrategy::AlphaNearness,
+            _ => CandidateStrategy::Nearest,
+        };
+        solver = solver.with_candidate_lists(strategy, candidate_list_size);
+    }
+
+    Ok(Box::new(solver))
+}
@@ -0,0 +1,92 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::cancellation::CancellationToken;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Termination {
+    MaxIterations(u32),
+    TimeLimit(Duration),
+    TargetScore(f64),
+    MaxStagnantIterations(u32),
+    MaxEvaluations(u64),
+    Cancelled(CancellationToken),
+    Any(Vec<Termination>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StopReason {
+    Completed,
+    MaxIterations,
+    TimeLimit,
+    TargetScore,
+    Stagnation,
+    MaxEvaluations,
+    Interrupted,
+    Eliminated,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SearchStatus {
+    pub iteration: u32,
+    pub stagnant_iterations: u32,
+    pub evaluations: u64,
+    pub elapsed: Duration,
+    pub best_score: Option<f64>,
+}
+
+impl Termination {
+    pub fn or(self, other: Termination) -> Termination {
+        match self {
+            Termination::Any(mut criteria) => {
+                criteria.push(other);
+                Termination::Any(criteria)
+            }
+            criterion => Termination::Any(vec![criterion, other]),
+        }
+    }
+
+    pub fn check(&self, status: &SearchStatus) -> Option<StopReason> {
+        match self {
+            Termination::MaxIterations(max_iterations) => {
+                (status.iteration >= *max_iterations).then_some(StopReason::MaxIterations)
+            }
+            Termination::TimeLimit(time_limit) => {
+                (status.elapsed >= *time_limit).then_some(StopReason::TimeLimit)
+            }
+            Termination::TargetScore(target_score) => status
+                .best_score
+                .filter(|best_score| best_score <= target_score)
+                .map(|_| StopReason::TargetScore),
+            Termination::MaxStagnantIterations(max_stagnant_iterations) => {
+                (status.stagnant_iterations >= *max_stagnant_iterations)
+                    .then_some(StopReason::Stagnation)
+            }
+            Termination::MaxEvaluations(max_evaluations) => {
+                (status.evaluations >= *max_evaluations).then_some(StopReason::MaxEvaluations)
+            }
+            Termination::Cancelled(cancellation_token) => cancellation_token
+                .is_cancelled()
+                .then_some(StopReason::Interrupted),
+            Termination::Any(criteria) => criteria
+                .iter()
+                .find_map(|criterion| criterion.check(status)),
+        }
+    }
+}
+
+impl Display for StopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            StopReason::Completed => "completed",
+            StopReason::MaxIterations => "max iterations reached",
+            StopReason::TimeLimit => "time limit reached",
+            StopReason::TargetScore => "target score reached",
+            StopReason::Stagnation => "no improvement",
+            StopReason::MaxEvaluations => "max evaluations reached",
+            StopReason::Interrupted => "interrupted",
+            StopReason::Eliminated => "eliminated by racing",
+        };
+
+        write!(f, "{reason}")
+    }
+}
@@ -0,0 +1,147 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::Add,
+};
+
+pub trait Weight: Copy + Default + PartialOrd + Display + Debug + Send + Sync {
+    type Score: Score;
+
+    const MAX: Self;
+
+    const INTEGRAL: bool;
+
+    fn to_score(self) -> Self::Score;
+
+    fn to_f64(self) -> f64;
+
+    fn from_f64(value: f64) -> Self;
+
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+pub trait Score:
+    Copy + Default + PartialOrd + Add<Output = Self> + Sum + Display + Debug + Send + Sync
+{
+    fn to_f64(self) -> f64;
+
+    fn from_bound(bound: f64) -> Self;
+
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    fn exceeds(self, other: Self) -> bool;
+}
+
+impl Weight for u32 {
+    type Score = u64;
+
+    const MAX: Self = u32::MAX;
+
+    const INTEGRAL: bool = true;
+
+    fn to_score(self) -> u64 {
+        u64::from(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as u32
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl Weight for u64 {
+    type Score = u64;
+
+    const MAX: Self = u64::MAX;
+
+    const INTEGRAL: bool = true;
+
+    fn to_score(self) -> u64 {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value.round() as u64
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+}
+
+impl Weight for f64 {
+    type Score = f64;
+
+    const MAX: Self = f64::INFINITY;
+
+    const INTEGRAL: bool = false;
+
+    fn to_score(self) -> f64 {
+        self
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+}
+
+impl Score for u64 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_bound(bound: f64) -> Self {
+        const RELATIVE_EPSILON: f64 = 1e-9;
+
+        let epsilon = RELATIVE_EPSILON * bound.abs().max(1.0);
+        (bound - epsilon).ceil().max(0.0) as u64
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    fn exceeds(self, other: Self) -> bool {
+        self > other
+    }
+}
+
+impl Score for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_bound(bound: f64) -> Self {
+        bound.max(0.0)
+    }
+
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+
+    fn exceeds(self, other: Self) -> bool {
+        const RELATIVE_EPSILON: f64 = 1e-9;
+
+        self > other + other.abs() * RELATIVE_EPSILON
+    }
+}
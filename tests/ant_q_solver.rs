@@ -0,0 +1,53 @@
+use cea::{
+    ant_q_solver::AntQSolver,
+    candidate_lists::CandidateStrategy,
+    instance::{CoordinateInstance, Metric},
+    models::Solver,
+};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+
+const SEED: u64 = 32;
+const NODES_COUNT: usize = 60;
+const MAX_ITERATIONS: u32 = 30;
+const THREADS_COUNTS: [usize; 2] = [1, 4];
+const CANDIDATE_LIST_SIZE: usize = 8;
+
+fn random_instance() -> CoordinateInstance<f64> {
+    let mut rng = Pcg64::seed_from_u64(SEED);
+    let coordinates = (0..NODES_COUNT)
+        .map(|_| (rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)))
+        .collect();
+
+    CoordinateInstance::new(coordinates, Metric::Euclidean)
+}
+
+fn ant_q_solver(threads_count: usize) -> AntQSolver {
+    AntQSolver::new(MAX_ITERATIONS, 10, Some(SEED), 1.0, 2.0, 1.0, 0.1)
+        .with_threads_count(threads_count)
+}
+
+#[test]
+fn threads_count_does_not_change_result() {
+    let instance = random_instance();
+
+    let [single, multiple] = THREADS_COUNTS
+        .map(|threads_count| ant_q_solver(threads_count).solve(&instance).way().clone());
+
+    assert_eq!(single, multiple);
+}
+
+#[test]
+fn threads_count_does_not_change_result_with_candidate_lists() {
+    let instance = random_instance();
+
+    let [single, multiple] = THREADS_COUNTS.map(|threads_count| {
+        ant_q_solver(threads_count)
+            .with_candidate_lists(CandidateStrategy::Nearest, CANDIDATE_LIST_SIZE)
+            .solve(&instance)
+            .way()
+            .clone()
+    });
+
+    assert_eq!(single, multiple);
+}
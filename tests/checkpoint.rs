@@ -0,0 +1,52 @@
+use std::{env, fs, process};
+
+use cea::{
+    ant_q_solver::AntQSolver,
+    candidate_lists::CandidateStrategy,
+    instance::{CoordinateInstance, Metric},
+    models::Solver,
+};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+
+const SEED: u64 = 44;
+const NODES_COUNT: usize = 60;
+const CHECKPOINT_ITERATION: u32 = 15;
+const MAX_ITERATIONS: u32 = 40;
+const CANDIDATE_LIST_SIZE: usize = 8;
+const THREADS_COUNT: usize = 4;
+
+fn random_instance() -> CoordinateInstance<f64> {
+    let mut rng = Pcg64::seed_from_u64(SEED);
+    let coordinates = (0..NODES_COUNT)
+        .map(|_| (rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)))
+        .collect();
+
+    CoordinateInstance::new(coordinates, Metric::Euclidean)
+}
+
+fn ant_q_solver(max_iterations: u32) -> AntQSolver {
+    AntQSolver::new(max_iterations, 10, Some(SEED), 1.0, 2.0, 1.0, 0.1)
+        .with_candidate_lists(CandidateStrategy::Nearest, CANDIDATE_LIST_SIZE)
+        .with_threads_count(THREADS_COUNT)
+}
+
+#[test]
+fn resumed_run_matches_uninterrupted_run() {
+    let instance = random_instance();
+    let path = env::temp_dir().join(format!("cea-checkpoint-{}.json", process::id()));
+
+    let straight = ant_q_solver(MAX_ITERATIONS).solve(&instance);
+
+    ant_q_solver(CHECKPOINT_ITERATION)
+        .with_checkpoints(&path, CHECKPOINT_ITERATION)
+        .solve(&instance);
+    let resumed = ant_q_solver(MAX_ITERATIONS)
+        .with_resume_from(&path)
+        .solve(&instance);
+
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(resumed.way(), straight.way());
+    assert_eq!(resumed.score(), straight.score());
+}
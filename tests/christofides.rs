@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+
+use cea::{
+    christofides_solver::{ChristofidesSolver, MatchingAlgorithm},
+    instance::{CoordinateInstance, Instance, Metric},
+    models::{AdjMatrix, SolveError, Solver},
+};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+
+const SEED: u64 = 26;
+const INSTANCES_PER_SIZE: usize = 20;
+const MAX_NODES_COUNT: usize = 8;
+const APPROXIMATION_RATIO: f64 = 1.5;
+const TOLERANCE: f64 = 1e-9;
+
+fn random_instance(rng: &mut Pcg64, nodes_count: usize) -> CoordinateInstance<f64> {
+    let coordinates = (0..nodes_count)
+        .map(|_| (rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)))
+        .collect();
+
+    CoordinateInstance::new(coordinates, Metric::Euclidean)
+}
+
+fn optimal_score<I: Instance<Weight = f64>>(instance: &I) -> f64 {
+    fn search<I: Instance<Weight = f64>>(
+        instance: &I,
+        way: &mut Vec<usize>,
+        visited: &mut Vec<bool>,
+        length: f64,
+        best: &mut f64,
+    ) {
+        let last = *way.last().unwrap();
+        if way.len() == instance.nodes_count() {
+            *best = best.min(length + instance.distance(last, way[0]));
+            return;
+        }
+
+        for node in 1..instance.nodes_count() {
+            if !visited[node] {
+                visited[node] = true;
+                way.push(node);
+                search(
+                    instance,
+                    way,
+                    visited,
+                    length + instance.distance(last, node),
+                    best,
+                );
+                way.pop();
+                visited[node] = false;
+            }
+        }
+    }
+
+    let mut best = f64::INFINITY;
+    let mut visited = vec![false; instance.nodes_count()];
+    visited[0] = true;
+    search(instance, &mut vec![0], &mut visited, 0.0, &mut best);
+
+    best
+}
+
+#[test]
+fn christofides_is_within_approximation_ratio() {
+    let mut rng = Pcg64::seed_from_u64(SEED);
+    let solver = ChristofidesSolver::new(MatchingAlgorithm::Exact);
+
+    for nodes_count in 3..=MAX_NODES_COUNT {
+        for _ in 0..INSTANCES_PER_SIZE {
+            let instance = random_instance(&mut rng, nodes_count);
+
+            let way = solver.try_solve(&instance).unwrap();
+            way.validate().unwrap();
+
+            let optimum = optimal_score(&instance);
+            assert!(way.score() <= APPROXIMATION_RATIO * optimum * (1.0 + TOLERANCE));
+        }
+    }
+}
+
+#[test]
+fn christofides_rejects_asymmetric_instance() {
+    let instance = AdjMatrix::from_fn(3, 3, |from, to| match from.cmp(&to) {
+        Ordering::Less => 1u32,
+        Ordering::Equal => 0,
+        Ordering::Greater => 2,
+    });
+    let solver = ChristofidesSolver::new(MatchingAlgorithm::Exact);
+
+    assert!(matches!(
+        solver.try_solve(&instance),
+        Err(SolveError::MatrixNotSymmetric { .. })
+    ));
+}
+
+#[test]
+fn christofides_rejects_non_metric_instance() {
+    let instance = AdjMatrix::from_fn(3, 3, |from, to| match (from.min(to), from.max(to)) {
+        (node, other) if node == other => 0u32,
+        (0, 2) => 10,
+        _ => 1,
+    });
+    let solver = ChristofidesSolver::new(MatchingAlgorithm::Exact);
+
+    assert!(matches!(
+        solver.try_solve(&instance),
+        Err(SolveError::TriangleInequalityViolated {
+            from: 0,
+            via: 1,
+            to: 2
+        })
+    ));
+}
+
+#[test]
+fn christofides_accepts_rounded_collinear_points() {
+    let instance: CoordinateInstance<u32> =
+        CoordinateInstance::new(vec![(0.0, 0.0), (1.4, 0.0), (2.8, 0.0)], Metric::Euclidean);
+    assert!(instance.distance(0, 2) > instance.distance(0, 1) + instance.distance(1, 2));
+
+    let solver = ChristofidesSolver::new(MatchingAlgorithm::Exact);
+    let way = solver.try_solve(&instance).unwrap();
+
+    way.validate().unwrap();
+}
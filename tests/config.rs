@@ -0,0 +1,64 @@
+use std::{env, fs, process};
+
+use cea::{
+    config::{ConfigError, InstanceConfig, OutputConfig, RunConfig, SolverConfig},
+    models::AdjMatrix,
+    rand_utils::RngAlgorithm,
+    registry::SolverRegistry,
+};
+
+const RESOLVES_COUNT: usize = 64;
+
+fn seedless_config() -> RunConfig {
+    RunConfig {
+        seed: None,
+        rng_algorithm: RngAlgorithm::default(),
+        instance: InstanceConfig::Random {
+            size: 10,
+            min: 1,
+            max: 100,
+        },
+        solver: SolverConfig {
+            name: "greedy".to_string(),
+            parameters: Default::default(),
+        },
+        termination: Default::default(),
+        output: OutputConfig::default(),
+    }
+}
+
+#[test]
+fn resolved_seedless_config_round_trips_through_toml() {
+    let registry = SolverRegistry::<AdjMatrix<u32>>::default();
+    let path = env::temp_dir().join(format!("cea-config-{}.toml", process::id()));
+
+    for _ in 0..RESOLVES_COUNT {
+        let config = seedless_config().resolve(&registry).unwrap();
+        assert!(config.seed.is_some());
+
+        fs::write(&path, config.to_toml().unwrap()).unwrap();
+        let loaded = RunConfig::load(&path).unwrap();
+
+        assert_eq!(loaded, config);
+    }
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn random_instance_with_min_above_max_is_rejected() {
+    let registry = SolverRegistry::<AdjMatrix<u32>>::default();
+    let config = RunConfig {
+        instance: InstanceConfig::Random {
+            size: 10,
+            min: 100,
+            max: 1,
+        },
+        ..seedless_config()
+    };
+
+    assert!(matches!(
+        config.resolve(&registry),
+        Err(ConfigError::InvalidRandomRange { min: 100, max: 1 })
+    ));
+}
@@ -0,0 +1,40 @@
+use cea::matrix::Matrix;
+
+const SIZE: usize = 4;
+
+fn symmetric_matrix() -> Matrix<u32> {
+    Matrix::from_fn(SIZE, SIZE, |row, column| (row + column) as u32)
+}
+
+#[test]
+fn symmetry_is_detected() {
+    assert!(symmetric_matrix().is_symmetric());
+    assert!(!Matrix::from_fn(SIZE, SIZE, |row, _| row as u32).is_symmetric());
+    assert!(!Matrix::new(SIZE, SIZE + 1, 0).is_symmetric());
+}
+
+#[test]
+fn mutation_invalidates_cached_symmetry() {
+    let mut matrix = symmetric_matrix();
+    assert!(matrix.is_symmetric());
+
+    matrix[0][1] = 10;
+    assert!(!matrix.is_symmetric());
+    matrix[1][0] = 10;
+    assert!(matrix.is_symmetric());
+
+    matrix.row_mut(2)[3] = 10;
+    assert!(!matrix.is_symmetric());
+    *matrix.get_mut(3, 2).unwrap() = 10;
+    assert!(matrix.is_symmetric());
+
+    matrix.as_mut_slice()[1] = 20;
+    assert!(!matrix.is_symmetric());
+    matrix.fill(1);
+    assert!(matrix.is_symmetric());
+
+    if let Some(row) = matrix.rows_mut().next() {
+        row[SIZE - 1] = 0;
+    }
+    assert!(!matrix.is_symmetric());
+}
@@ -0,0 +1,59 @@
+use std::{env, fs, process, time::Duration};
+
+use cea::observer::{ConsoleObserver, CsvObserver, Observer, Progress};
+
+const ITERATIONS_COUNT: u32 = 5;
+
+fn progress(iteration: u32) -> Progress<u64> {
+    Progress {
+        iteration,
+        iteration_best_score: 100 - u64::from(iteration),
+        best_score: 90 - u64::from(iteration),
+        elapsed: Duration::from_millis(u64::from(iteration) * 10),
+        diversity: iteration.is_multiple_of(2).then_some(0.5),
+        restarts: iteration / 3,
+    }
+}
+
+#[test]
+fn csv_observer_writes_header_and_rows() {
+    let path = env::temp_dir().join(format!("cea-progress-{}.csv", process::id()));
+
+    let mut observer = CsvObserver::create(&path).unwrap();
+    for iteration in 1..=ITERATIONS_COUNT {
+        observer.on_iteration(&progress(iteration));
+    }
+    observer.finish().unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            "iteration,iteration_best_score,best_score,elapsed_ms,diversity,restarts",
+            "1,99,89,10.000,,0",
+            "2,98,88,20.000,0.5,0",
+            "3,97,87,30.000,,1",
+            "4,96,86,40.000,0.5,1",
+            "5,95,85,50.000,,1",
+        ]
+    );
+}
+
+#[test]
+fn console_observer_reports_every_interval() {
+    let mut observer = ConsoleObserver::with_writer(Vec::new(), 2);
+    for iteration in 1..=ITERATIONS_COUNT {
+        Observer::<u64>::on_iteration(&mut observer, &progress(iteration));
+    }
+
+    let output = String::from_utf8(observer.into_inner()).unwrap();
+    let iterations: Vec<&str> = output
+        .lines()
+        .filter_map(|line| line.split(':').next())
+        .collect();
+
+    assert_eq!(iterations, ["Iteration 2", "Iteration 4"]);
+}
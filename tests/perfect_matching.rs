@@ -0,0 +1,119 @@
+use cea::{
+    instance::Instance,
+    models::AdjMatrix,
+    perfect_matching::{exact_perfect_matching, greedy_perfect_matching},
+};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+
+const SEED: u64 = 26;
+const GRAPHS_PER_SIZE: usize = 50;
+const MAX_NODES_COUNT: usize = 10;
+
+fn random_graph(rng: &mut Pcg64, nodes_count: usize) -> AdjMatrix<u32> {
+    let mut graph = AdjMatrix::square(nodes_count, 0);
+    for from in 0..nodes_count {
+        for to in from + 1..nodes_count {
+            let weight = rng.gen_range(1..=100);
+            graph[from][to] = weight;
+            graph[to][from] = weight;
+        }
+    }
+
+    graph
+}
+
+fn matching_weight(graph: &AdjMatrix<u32>, matching: &[(usize, usize)]) -> u32 {
+    matching
+        .iter()
+        .map(|(from, to)| graph.distance(*from, *to))
+        .sum()
+}
+
+fn brute_force_weight(graph: &AdjMatrix<u32>, unmatched: &[usize]) -> u32 {
+    let Some((first, rest)) = unmatched.split_first() else {
+        return 0;
+    };
+
+    (0..rest.len())
+        .map(|index| {
+            let mut remaining = rest.to_vec();
+            let second = remaining.remove(index);
+
+            graph.distance(*first, second) + brute_force_weight(graph, &remaining)
+        })
+        .min()
+        .unwrap()
+}
+
+fn assert_perfect(nodes: &[usize], matching: &[(usize, usize)]) {
+    let mut matched: Vec<usize> = matching
+        .iter()
+        .flat_map(|(from, to)| [*from, *to])
+        .collect();
+    matched.sort_unstable();
+
+    assert_eq!(matched, nodes);
+}
+
+#[test]
+fn exact_matching_is_minimum_perfect_matching() {
+    let mut rng = Pcg64::seed_from_u64(SEED);
+
+    for nodes_count in (2..=MAX_NODES_COUNT).step_by(2) {
+        for _ in 0..GRAPHS_PER_SIZE {
+            let graph = random_graph(&mut rng, nodes_count);
+            let nodes: Vec<usize> = (0..nodes_count).collect();
+
+            let matching = exact_perfect_matching(&graph, &nodes);
+
+            assert_perfect(&nodes, &matching);
+            assert_eq!(
+                matching_weight(&graph, &matching),
+                brute_force_weight(&graph, &nodes)
+            );
+        }
+    }
+}
+
+#[test]
+fn exact_matching_of_node_subset_is_minimum_perfect_matching() {
+    let mut rng = Pcg64::seed_from_u64(SEED);
+
+    for nodes_count in (2..=MAX_NODES_COUNT).step_by(2) {
+        for _ in 0..GRAPHS_PER_SIZE {
+            let graph = random_graph(&mut rng, 2 * nodes_count);
+            let nodes: Vec<usize> = (0..2 * nodes_count).step_by(2).collect();
+
+            let matching = exact_perfect_matching(&graph, &nodes);
+
+            assert_perfect(&nodes, &matching);
+            assert_eq!(
+                matching_weight(&graph, &matching),
+                brute_force_weight(&graph, &nodes)
+            );
+        }
+    }
+}
+
+#[test]
+fn greedy_matching_is_perfect() {
+    let mut rng = Pcg64::seed_from_u64(SEED);
+
+    for nodes_count in (2..=MAX_NODES_COUNT).step_by(2) {
+        let graph = random_graph(&mut rng, nodes_count);
+        let nodes: Vec<usize> = (0..nodes_count).collect();
+
+        let matching = greedy_perfect_matching(&graph, &nodes);
+
+        assert_perfect(&nodes, &matching);
+        assert!(matching_weight(&graph, &matching) >= brute_force_weight(&graph, &nodes));
+    }
+}
+
+#[test]
+fn exact_matching_of_empty_node_set_is_empty() {
+    let graph = AdjMatrix::<u32>::square(0, 0);
+
+    assert!(exact_perfect_matching(&graph, &[]).is_empty());
+}
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use cea::{
+    ant_q_solver::AntQSolver,
+    cancellation::CancellationToken,
+    greedy_solver::GreedySolver,
+    instance::{CoordinateInstance, Metric},
+    models::Solver,
+    portfolio_solver::PortfolioSolver,
+    rand_utils::RngAlgorithm,
+    registry::{Arguments, BuildContext, RegistryError, SolverRegistry},
+    termination::StopReason,
+};
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64;
+
+const SEED: u64 = 48;
+const NODES_COUNT: usize = 100;
+const TIME_LIMIT: Duration = Duration::from_millis(200);
+const MEMBER_PARAMETERS: &str = "max-iterations=3:population-size=2:pheromone-evaporation=0.5";
+
+fn random_instance() -> CoordinateInstance<f64> {
+    let mut rng = Pcg64::seed_from_u64(SEED);
+    let coordinates = (0..NODES_COUNT)
+        .map(|_| (rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)))
+        .collect();
+
+    CoordinateInstance::new(coordinates, Metric::Euclidean)
+}
+
+#[test]
+fn portfolio_reports_time_limit_and_member_breakdown() {
+    let instance = random_instance();
+    let solver = PortfolioSolver::new(TIME_LIMIT)
+        .with_solver("greedy", GreedySolver {})
+        .with_solver(
+            "ant-q",
+            AntQSolver::new(u32::MAX, 10, Some(SEED), 1.0, 2.0, 1.0, 0.1),
+        );
+
+    let solution = solver.try_solve_with_observer(&instance, &mut ()).unwrap();
+
+    assert_eq!(solution.stop_reason(), StopReason::TimeLimit);
+
+    let entries = solution.portfolio_entries();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].name, "greedy");
+    assert_eq!(entries[0].stop_reason, Some(StopReason::Completed));
+    assert_eq!(entries[1].name, "ant-q");
+    assert_eq!(entries[1].stop_reason, Some(StopReason::TimeLimit));
+
+    let best_score = entries
+        .iter()
+        .filter_map(|entry| entry.score)
+        .min_by(f64::total_cmp)
+        .unwrap();
+    assert_eq!(solution.way().score(), best_score);
+}
+
+fn build_context() -> BuildContext {
+    BuildContext {
+        rng_algorithm: RngAlgorithm::default(),
+        random_seed: Some(SEED),
+        cancellation_token: CancellationToken::new(),
+    }
+}
+
+#[test]
+fn portfolio_members_receive_their_parameters() {
+    let instance = random_instance();
+    let registry = SolverRegistry::<CoordinateInstance<f64>>::default();
+    let context = build_context();
+
+    let member = format!("ant-q:{MEMBER_PARAMETERS}");
+    let portfolio = registry
+        .build(
+            "portfolio",
+            &Arguments::new().with("solvers", member.as_str()),
+            &context,
+        )
+        .unwrap();
+    let arguments = MEMBER_PARAMETERS
+        .split(':')
+        .filter_map(|parameter| parameter.split_once('='))
+        .fold(Arguments::new(), |arguments, (name, value)| {
+            arguments.with(name, value)
+        });
+    let ant_q = registry.build("ant-q", &arguments, &context).unwrap();
+
+    let solution = portfolio
+        .try_solve_with_observer(&instance, &mut ())
+        .unwrap();
+    let way = ant_q.try_solve(&instance).unwrap();
+
+    assert_eq!(solution.way().way(), way.way());
+    assert_eq!(solution.portfolio_entries()[0].name, member);
+}
+
+#[test]
+fn portfolio_rejects_invalid_member_parameters() {
+    let registry = SolverRegistry::<CoordinateInstance<f64>>::default();
+    let context = build_context();
+    let build = |solvers: &str| {
+        registry
+            .build(
+                "portfolio",
+                &Arguments::new().with("solvers", solvers),
+                &context,
+            )
+            .err()
+    };
+
+    assert!(matches!(
+        build("greedy,ant-q:alpha=0.2"),
+        Some(RegistryError::UnknownParameter { .. })
+    ));
+    assert!(matches!(
+        build("ant-q:population-size"),
+        Some(RegistryError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        build("ant-q:population-size=many"),
+        Some(RegistryError::InvalidValue { .. })
+    ));
+}
@@ -0,0 +1,131 @@
+use std::{cmp::Ordering, collections::HashSet};
+
+use cea::{
+    instance_analysis::Symmetry,
+    models::{population_diversity, AdjMatrix, Way, WayError, WayVecExt},
+};
+
+const NODES_COUNT: usize = 4;
+
+fn symmetric_instance() -> AdjMatrix<u32> {
+    AdjMatrix::from_fn(NODES_COUNT, NODES_COUNT, |from, to| u32::from(from != to))
+}
+
+fn asymmetric_instance() -> AdjMatrix<u32> {
+    AdjMatrix::from_fn(NODES_COUNT, NODES_COUNT, |from, to| {
+        (from != to) as u32 + (from > to) as u32
+    })
+}
+
+#[test]
+fn canonical_starts_at_smallest_node() {
+    let way = vec![2, 0, 3, 1, 2];
+
+    assert_eq!(way.canonical(Symmetry::Symmetric), vec![0, 2, 1, 3, 0]);
+    assert_eq!(way.canonical(Symmetry::Asymmetric), vec![0, 3, 1, 2, 0]);
+    assert_eq!(
+        Vec::new().canonical(Symmetry::Symmetric),
+        Vec::<usize>::new()
+    );
+}
+
+#[test]
+fn validate_reports_invalid_ways() {
+    assert_eq!(vec![0, 1, 2, 3, 0].validate(NODES_COUNT), Ok(()));
+    assert_eq!(Vec::new().validate(NODES_COUNT), Err(WayError::Empty));
+    assert_eq!(Vec::new().validate(0), Ok(()));
+    assert_eq!(
+        vec![0, 1, 4, 3, 0].validate(NODES_COUNT),
+        Err(WayError::IndexOutOfRange {
+            position: 2,
+            node: 4
+        })
+    );
+    assert_eq!(
+        vec![0, 1, 2, 3].validate(NODES_COUNT),
+        Err(WayError::NotClosed { first: 0, last: 3 })
+    );
+    assert_eq!(
+        vec![0, 1, 1, 3, 0].validate(NODES_COUNT),
+        Err(WayError::NotPermutation { node: 1, visits: 2 })
+    );
+}
+
+#[test]
+fn ways_are_equal_up_to_rotation_and_symmetric_reversal() {
+    let symmetric = symmetric_instance();
+    let asymmetric = asymmetric_instance();
+
+    let way = Way::new(&symmetric, vec![0, 1, 2, 3, 0]);
+    let rotated = Way::new(&symmetric, vec![2, 3, 0, 1, 2]);
+    let reversed = Way::new(&symmetric, vec![0, 3, 2, 1, 0]);
+    let other = Way::new(&symmetric, vec![0, 2, 1, 3, 0]);
+
+    assert!(way == rotated);
+    assert!(way == reversed);
+    assert!(way != other);
+    assert_eq!(
+        [&way, &rotated, &reversed, &other]
+            .into_iter()
+            .collect::<HashSet<_>>()
+            .len(),
+        2
+    );
+
+    let way = Way::new(&asymmetric, vec![0, 1, 2, 3, 0]);
+    let rotated = Way::new(&asymmetric, vec![2, 3, 0, 1, 2]);
+    let reversed = Way::new(&asymmetric, vec![0, 3, 2, 1, 0]);
+
+    assert!(way == rotated);
+    assert!(way != reversed);
+}
+
+#[test]
+fn ordering_agrees_with_equality() {
+    let instance = symmetric_instance();
+    let ways = [
+        Way::new(&instance, vec![0, 1, 2, 3, 0]),
+        Way::new(&instance, vec![1, 0, 3, 2, 1]),
+        Way::new(&instance, vec![0, 2, 1, 3, 0]),
+        Way::new(&instance, vec![0, 1, 3, 2, 0]),
+    ];
+
+    for first in &ways {
+        for second in &ways {
+            assert_eq!(first.cmp(second) == Ordering::Equal, first == second);
+            assert_eq!(first.cmp(second), second.cmp(first).reverse());
+        }
+    }
+}
+
+#[test]
+fn edge_metrics_compare_tours() {
+    let instance = symmetric_instance();
+    let way = Way::new(&instance, vec![0, 1, 2, 3, 0]);
+    let reversed = Way::new(&instance, vec![0, 3, 2, 1, 0]);
+    let other = Way::new(&instance, vec![0, 2, 1, 3, 0]);
+
+    assert_eq!(way.shared_edges_count(&reversed), 4);
+    assert_eq!(way.bond_distance(&reversed), 0);
+    assert_eq!(way.edge_overlap(&reversed), 1.0);
+    assert_eq!(way.positional_distance(&reversed), 0);
+
+    assert_eq!(way.shared_edges_count(&other), 2);
+    assert_eq!(way.bond_distance(&other), 2);
+    assert_eq!(way.edge_overlap(&other), 0.5);
+    assert_eq!(way.positional_distance(&other), 2);
+
+    let diversity = population_diversity(&[way, reversed, other]);
+    assert!((diversity - 1.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn reversed_tour_shares_no_edges_on_asymmetric_instance() {
+    let instance = asymmetric_instance();
+    let way = Way::new(&instance, vec![0, 1, 2, 3, 0]);
+    let reversed = Way::new(&instance, vec![0, 3, 2, 1, 0]);
+
+    assert_eq!(way.shared_edges_count(&reversed), 0);
+    assert_eq!(way.bond_distance(&reversed), 4);
+    assert_eq!(way.edge_overlap(&reversed), 0.0);
+}